@@ -0,0 +1,34 @@
+use crate::Key;
+
+/// A set of changes which transforms one [`Slab`](crate::Slab) into another.
+///
+/// Created by [`Slab::diff`](crate::Slab::diff), and applied with
+/// [`Slab::apply_delta`](crate::Slab::apply_delta).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlabDelta<T> {
+    /// The key-value pairs to insert, after all removes have been applied.
+    pub inserts: Vec<(Key, T)>,
+    /// The keys to remove.
+    pub removes: Vec<Key>,
+}
+
+impl<T> SlabDelta<T> {
+    /// Creates an empty `SlabDelta`.
+    pub fn new() -> Self {
+        Self {
+            inserts: vec![],
+            removes: vec![],
+        }
+    }
+
+    /// Returns `true` if the delta contains no changes.
+    pub fn is_empty(&self) -> bool {
+        self.inserts.is_empty() && self.removes.is_empty()
+    }
+}
+
+impl<T> Default for SlabDelta<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
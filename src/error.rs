@@ -0,0 +1,58 @@
+use crate::Key;
+
+use std::fmt;
+
+/// The error returned by [`Slab::batch_insert_at`](crate::Slab::batch_insert_at).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BatchInsertError {
+    /// The key is already occupied in the slab.
+    Occupied(Key),
+    /// The key appears more than once in the batch.
+    Duplicate(Key),
+}
+
+impl BatchInsertError {
+    /// Returns the key which caused the conflict.
+    pub fn key(&self) -> Key {
+        match self {
+            Self::Occupied(key) | Self::Duplicate(key) => *key,
+        }
+    }
+}
+
+impl fmt::Display for BatchInsertError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Occupied(key) => write!(f, "key {} is already occupied", key.index()),
+            Self::Duplicate(key) => write!(f, "key {} appears more than once", key.index()),
+        }
+    }
+}
+
+impl std::error::Error for BatchInsertError {}
+
+/// The error returned by [`Slab::try_shrink_to`](crate::Slab::try_shrink_to).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShrinkError {
+    /// Shrinking would remove occupied keys.
+    OccupiedKeysInTruncatedRange {
+        /// The occupied keys which fall in the truncated range.
+        keys: Vec<Key>,
+    },
+}
+
+impl fmt::Display for ShrinkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OccupiedKeysInTruncatedRange { keys } => {
+                write!(
+                    f,
+                    "{} occupied keys fall in the truncated range",
+                    keys.len()
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ShrinkError {}
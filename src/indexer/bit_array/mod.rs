@@ -1,4 +1,4 @@
-use super::utils::compute_index;
+use super::utils::{compute_index, rank};
 pub(crate) use into_occupied::IntoOccupied;
 pub(crate) use occupied::Occupied;
 pub(crate) use unoccupied::UnOccupied;
@@ -8,7 +8,7 @@ mod occupied;
 mod unoccupied;
 
 /// An indexing structure implemented as a bit-tree.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) struct BitArray<const N: usize> {
     entries: [usize; N],
 }
@@ -41,8 +41,8 @@ impl<const N: usize> BitArray<N> {
     /// Remove an entry from the index
     #[inline]
     pub(crate) fn remove(&mut self, index: usize) -> bool {
-        let (index, mask) = compute_index(index);
         let ret = self.contains(index);
+        let (index, mask) = compute_index(index);
         match self.entries.get_mut(index) {
             Some(entry) => {
                 *entry &= !mask;
@@ -89,6 +89,18 @@ impl<const N: usize> BitArray<N> {
         true
     }
 
+    /// How many items are contained before `index`?
+    #[inline]
+    pub(crate) fn rank(&self, index: usize) -> usize {
+        rank(&self.entries, index)
+    }
+
+    /// Access the words which make up the index.
+    #[inline]
+    pub(crate) fn words(&self) -> &[usize] {
+        &self.entries
+    }
+
     /// What is the current capacity?
     #[inline]
     pub(crate) fn capacity(&self) -> usize {
@@ -112,6 +124,13 @@ impl<const N: usize> BitArray<N> {
     pub(crate) fn unoccupied(&self) -> UnOccupied<N> {
         UnOccupied::new(self)
     }
+
+    /// Create an iterator over the indexes not occupied by items, starting at
+    /// `index`.
+    #[inline]
+    pub(crate) fn unoccupied_from(&self, index: usize) -> UnOccupied<'_, N> {
+        UnOccupied::starting_from(self, index)
+    }
 }
 
 #[cfg(test)]
@@ -156,4 +175,27 @@ mod test {
         }
         assert_eq!(count, max);
     }
+
+    #[test]
+    fn occupied_bounds() {
+        const LEN: usize = 2;
+        let mut arr: BitArray<LEN> = BitArray::new();
+        let last = arr.capacity() - 1;
+        for index in [0, 1, 63, 64, last] {
+            arr.insert(index);
+        }
+
+        let mut occupied = arr.occupied();
+        assert_eq!(occupied.next(), Some(0));
+        assert_eq!(occupied.next(), Some(1));
+        assert_eq!(occupied.nth(1), Some(64));
+        assert_eq!(occupied.next(), Some(last));
+        assert_eq!(occupied.next(), None);
+        assert_eq!(occupied.next(), None);
+
+        let mut occupied = arr.occupied();
+        assert_eq!(occupied.nth(2), Some(63));
+        assert_eq!(occupied.nth(1), Some(last));
+        assert_eq!(occupied.next(), None);
+    }
 }
@@ -1,4 +1,5 @@
 use super::BitArray;
+use crate::indexer::utils::{compute_index, select_bit};
 #[derive(Debug)]
 pub(crate) struct Occupied<'a, const N: usize> {
     /// What is the current index of the cursor?
@@ -29,6 +30,8 @@ impl<'a, const N: usize> Iterator for Occupied<'a, N> {
             return None;
         }
 
+        // The range is evaluated once, so `cursor` always points one past the
+        // last index we looked at.
         for index in self.cursor..self.bit_array.capacity() {
             self.cursor += 1;
             match self.bit_array.contains(index) {
@@ -41,4 +44,33 @@ impl<'a, const N: usize> Iterator for Occupied<'a, N> {
         }
         None
     }
+
+    #[inline]
+    fn nth(&mut self, mut n: usize) -> Option<Self::Item> {
+        if n >= self.remaining {
+            self.remaining = 0;
+            return None;
+        }
+
+        // Skip over entire words at a time by counting how many bits are set
+        // in each, until we find the word containing the bit we want.
+        let bits = usize::BITS as usize;
+        while self.cursor < self.bit_array.capacity() {
+            let (word_index, _) = compute_index(self.cursor);
+            let offset = self.cursor % bits;
+            let word = self.bit_array.entries.get(word_index).copied().unwrap_or(0);
+            let word = word & (usize::MAX << offset);
+            let count = word.count_ones() as usize;
+            if n < count {
+                let index = word_index * bits + select_bit(word, n);
+                self.cursor = index + 1;
+                self.remaining -= n + 1;
+                return Some(index);
+            }
+            n -= count;
+            self.remaining -= count;
+            self.cursor = (word_index + 1) * bits;
+        }
+        None
+    }
 }
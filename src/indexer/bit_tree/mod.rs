@@ -78,11 +78,13 @@ impl BitVec {
     /// Remove an entry from the index
     #[inline]
     pub(crate) fn remove(&mut self, index: usize) -> bool {
-        let (index, mask) = compute_index(index);
         let ret = self.contains(index);
+        let (index, mask) = compute_index(index);
         match self.entries.get_mut(index) {
             Some(entry) => {
-                self.count -= 1;
+                if ret {
+                    self.count -= 1;
+                }
                 *entry &= !mask;
                 ret
             }
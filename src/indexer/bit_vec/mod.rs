@@ -1,4 +1,4 @@
-use super::utils::compute_index;
+use super::utils::{compute_index, rank};
 pub(crate) use into_occupied::IntoOccupied;
 pub(crate) use occupied::Occupied;
 pub(crate) use unoccupied::UnOccupied;
@@ -8,7 +8,7 @@ mod occupied;
 mod unoccupied;
 
 /// An indexing structure implemented as a bit-tree.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) struct BitVec {
     entries: Vec<usize>,
     count: usize,
@@ -32,7 +32,7 @@ impl BitVec {
     #[allow(unused)]
     pub(crate) fn with_capacity(capacity: usize) -> Self {
         Self {
-            entries: vec![0; capacity],
+            entries: vec![0; compute_len(capacity)],
             count: 0,
         }
     }
@@ -41,7 +41,7 @@ impl BitVec {
     #[inline]
     pub(crate) fn insert(&mut self, index: usize) {
         if index >= self.capacity() {
-            self.resize(self.capacity() * 2);
+            self.resize((self.capacity() * 2).max(index + 1));
         }
         let (index, mask) = compute_index(index);
         self.entries[index] |= mask;
@@ -51,11 +51,13 @@ impl BitVec {
     /// Remove an entry from the index
     #[inline]
     pub(crate) fn remove(&mut self, index: usize) -> bool {
-        let (index, mask) = compute_index(index);
         let ret = self.contains(index);
+        let (index, mask) = compute_index(index);
         match self.entries.get_mut(index) {
             Some(entry) => {
-                self.count -= 1;
+                if ret {
+                    self.count -= 1;
+                }
                 *entry &= !mask;
                 ret
             }
@@ -92,16 +94,29 @@ impl BitVec {
         self.len() == 0
     }
 
+    /// How many items are contained before `index`?
+    #[inline]
+    pub(crate) fn rank(&self, index: usize) -> usize {
+        rank(&self.entries, index)
+    }
+
+    /// Access the words which make up the index.
+    #[inline]
+    pub(crate) fn words(&self) -> &[usize] {
+        &self.entries
+    }
+
     /// What is the current capacity?
     #[inline]
     pub(crate) fn capacity(&self) -> usize {
-        usize::BITS as usize * self.entries.capacity()
+        usize::BITS as usize * self.entries.len()
     }
 
     /// Resize the Index
     #[inline]
     pub(crate) fn resize(&mut self, new_len: usize) {
         let current_length = self.entries.len();
+        let new_len = compute_len(new_len);
         self.entries.resize(new_len, 0);
 
         if new_len < current_length {
@@ -113,6 +128,12 @@ impl BitVec {
         }
     }
 
+    /// Release any excess memory held by the Index
+    #[inline]
+    pub(crate) fn shrink_to_fit(&mut self) {
+        self.entries.shrink_to_fit();
+    }
+
     /// Create an iterator over the indexes occupied by items.
     #[inline]
     pub(crate) fn occupied(&self) -> Occupied {
@@ -130,12 +151,38 @@ impl BitVec {
     pub(crate) fn unoccupied(&self) -> UnOccupied {
         UnOccupied::new(self)
     }
+
+    /// Create an iterator over the indexes not occupied by items, starting at
+    /// `index`.
+    #[inline]
+    pub(crate) fn unoccupied_from(&self, index: usize) -> UnOccupied<'_> {
+        UnOccupied::starting_from(self, index)
+    }
+}
+
+/// How many words do we need to hold `capacity` bits?
+#[inline]
+const fn compute_len(capacity: usize) -> usize {
+    capacity.div_ceil(usize::BITS as usize)
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
 
+    #[test]
+    fn capacity() {
+        assert_eq!(BitVec::with_capacity(0).capacity(), 0);
+        assert_eq!(BitVec::with_capacity(1).capacity(), 64);
+        assert_eq!(BitVec::with_capacity(64).capacity(), 64);
+        assert_eq!(BitVec::with_capacity(65).capacity(), 128);
+
+        let mut arr = BitVec::new();
+        arr.insert(130);
+        assert!(arr.contains(130));
+        assert_eq!(arr.capacity(), 192);
+    }
+
     #[test]
     fn smoke() {
         let mut arr = BitVec::with_capacity(2);
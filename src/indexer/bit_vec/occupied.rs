@@ -1,4 +1,5 @@
 use super::BitVec;
+use crate::indexer::utils::{compute_index, select_bit};
 
 #[derive(Debug)]
 pub(crate) struct Occupied<'a> {
@@ -42,4 +43,33 @@ impl<'a> Iterator for Occupied<'a> {
         }
         None
     }
+
+    #[inline]
+    fn nth(&mut self, mut n: usize) -> Option<Self::Item> {
+        if n >= self.remaining {
+            self.remaining = 0;
+            return None;
+        }
+
+        // Skip over entire words at a time by counting how many bits are set
+        // in each, until we find the word containing the bit we want.
+        let bits = usize::BITS as usize;
+        while self.cursor < self.bit_array.capacity() {
+            let (word_index, _) = compute_index(self.cursor);
+            let offset = self.cursor % bits;
+            let word = self.bit_array.entries.get(word_index).copied().unwrap_or(0);
+            let word = word & (usize::MAX << offset);
+            let count = word.count_ones() as usize;
+            if n < count {
+                let index = word_index * bits + select_bit(word, n);
+                self.cursor = index + 1;
+                self.remaining -= n + 1;
+                return Some(index);
+            }
+            n -= count;
+            self.remaining -= count;
+            self.cursor = (word_index + 1) * bits;
+        }
+        None
+    }
 }
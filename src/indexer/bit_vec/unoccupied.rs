@@ -1,4 +1,5 @@
 use super::BitVec;
+use crate::indexer::utils::{compute_index, select_bit};
 
 #[derive(Debug)]
 pub(crate) struct UnOccupied<'a> {
@@ -19,6 +20,19 @@ impl<'a> UnOccupied<'a> {
             bit_array,
         }
     }
+
+    /// Create an iterator over the unoccupied indexes starting at `index`.
+    #[inline]
+    pub(crate) fn starting_from(bit_array: &'a BitVec, index: usize) -> Self {
+        let capacity = bit_array.capacity();
+        let index = index.min(capacity);
+        let occupied = bit_array.len() - bit_array.rank(index);
+        Self {
+            cursor: index,
+            remaining: capacity - index - occupied,
+            bit_array,
+        }
+    }
 }
 
 impl<'a> Iterator for UnOccupied<'a> {
@@ -30,25 +44,49 @@ impl<'a> Iterator for UnOccupied<'a> {
             return None;
         }
 
-        for index in self.cursor..self.bit_array.capacity() {
-            // Check once per byte whether the entire byte is set. If it is we
-            // can skip to the next byte. If it isn't, we iterate over it.
-            if (index % usize::BITS as usize) == 0 {
-                let byte_position = index / (usize::BITS as usize);
-                if self.bit_array.entries[byte_position] == usize::MAX {
-                    self.cursor += usize::BITS as usize;
-                    continue;
-                }
-            } else {
-                self.cursor += 1;
+        // Skip over entire words at a time until we find one with a free bit.
+        let bits = usize::BITS as usize;
+        while self.cursor < self.bit_array.capacity() {
+            let (word_index, _) = compute_index(self.cursor);
+            let offset = self.cursor % bits;
+            let word = self.bit_array.entries.get(word_index).copied().unwrap_or(0);
+            let word = !word & (usize::MAX << offset);
+            if word != 0 {
+                let index = word_index * bits + word.trailing_zeros() as usize;
+                self.cursor = index + 1;
+                self.remaining -= 1;
+                return Some(index);
             }
-            match self.bit_array.contains(index) {
-                false => {
-                    self.remaining -= 1;
-                    return Some(index);
-                }
-                true => continue,
+            self.cursor = (word_index + 1) * bits;
+        }
+        None
+    }
+
+    #[inline]
+    fn nth(&mut self, mut n: usize) -> Option<Self::Item> {
+        if n >= self.remaining {
+            self.remaining = 0;
+            return None;
+        }
+
+        // Skip over entire words at a time by counting how many bits are set
+        // in each, until we find the word containing the bit we want.
+        let bits = usize::BITS as usize;
+        while self.cursor < self.bit_array.capacity() {
+            let (word_index, _) = compute_index(self.cursor);
+            let offset = self.cursor % bits;
+            let word = self.bit_array.entries.get(word_index).copied().unwrap_or(0);
+            let word = !word & (usize::MAX << offset);
+            let count = word.count_ones() as usize;
+            if n < count {
+                let index = word_index * bits + select_bit(word, n);
+                self.cursor = index + 1;
+                self.remaining -= n + 1;
+                return Some(index);
             }
+            n -= count;
+            self.remaining -= count;
+            self.cursor = (word_index + 1) * bits;
         }
         None
     }
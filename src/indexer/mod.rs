@@ -5,18 +5,19 @@ mod utils;
 
 use bit_array::BitArray;
 use bit_vec::BitVec;
+use utils::compute_index;
 
 /// How many bits should our in-line strucutre hold?
 const CAPACITY: usize = 2;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 enum Inner {
     BitVec(BitVec),
     BitArray(BitArray<CAPACITY>),
 }
 
 /// An indexing structure with variable backends.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) struct Indexer {
     inner: Inner,
 }
@@ -95,6 +96,50 @@ impl Indexer {
         }
     }
 
+    /// Returns `true` if the index contains all values
+    #[inline]
+    pub(crate) fn contains_all(&self, indexes: impl IntoIterator<Item = usize>) -> bool {
+        let words = self.words();
+        indexes.into_iter().all(|index| {
+            let (index, mask) = compute_index(index);
+            match words.get(index) {
+                Some(word) => word & mask == mask,
+                None => false,
+            }
+        })
+    }
+
+    /// Find the lowest index occupied by an item, starting at `index`.
+    #[inline]
+    pub(crate) fn next_occupied(&self, index: usize) -> Option<usize> {
+        let words = self.words();
+        let (mut word_index, _) = compute_index(index);
+        let offset = index % usize::BITS as usize;
+        let mut word = words.get(word_index)? & (usize::MAX << offset);
+        while word == 0 {
+            word_index += 1;
+            word = *words.get(word_index)?;
+        }
+        Some(word_index * usize::BITS as usize + word.trailing_zeros() as usize)
+    }
+
+    /// Find the lowest index occupied by an item.
+    #[inline]
+    pub(crate) fn first_occupied(&self) -> Option<usize> {
+        let words = self.words();
+        let word_index = words.iter().position(|word| *word != 0)?;
+        Some(word_index * usize::BITS as usize + words[word_index].trailing_zeros() as usize)
+    }
+
+    /// Find the highest index occupied by an item.
+    #[inline]
+    pub(crate) fn last_occupied(&self) -> Option<usize> {
+        let words = self.words();
+        let word_index = words.iter().rposition(|word| *word != 0)?;
+        let bit = usize::BITS - 1 - words[word_index].leading_zeros();
+        Some(word_index * usize::BITS as usize + bit as usize)
+    }
+
     /// How many items are currently contained?
     #[inline]
     pub(crate) fn len(&self) -> usize {
@@ -113,6 +158,24 @@ impl Indexer {
         }
     }
 
+    /// How many items are contained before `index`?
+    #[inline]
+    pub(crate) fn rank(&self, index: usize) -> usize {
+        match &self.inner {
+            Inner::BitVec(vec) => vec.rank(index),
+            Inner::BitArray(vec) => vec.rank(index),
+        }
+    }
+
+    /// Access the words which make up the index.
+    #[inline]
+    pub(crate) fn words(&self) -> &[usize] {
+        match &self.inner {
+            Inner::BitVec(vec) => vec.words(),
+            Inner::BitArray(vec) => vec.words(),
+        }
+    }
+
     /// What is the current capacity?
     #[inline]
     pub(crate) fn capacity(&self) -> usize {
@@ -139,6 +202,17 @@ impl Indexer {
         }
     }
 
+    /// Shrink the Index to the smallest capacity which holds every occupied
+    /// index.
+    #[inline]
+    pub(crate) fn shrink_to_fit(&mut self) {
+        let new_len = self.last_occupied().map_or(0, |index| index + 1);
+        if let Inner::BitVec(vec) = &mut self.inner {
+            vec.resize(new_len);
+            vec.shrink_to_fit();
+        }
+    }
+
     /// Create an iterator over the indexes occupied by items.
     #[inline]
     pub(crate) fn occupied(&self) -> Occupied {
@@ -156,6 +230,29 @@ impl Indexer {
     pub(crate) fn unoccupied(&self) -> UnOccupied {
         UnOccupied::new(self)
     }
+
+    /// Create an iterator over the indexes not occupied by items, starting at
+    /// `index`.
+    #[inline]
+    pub(crate) fn unoccupied_from(&self, index: usize) -> UnOccupied<'_> {
+        UnOccupied::starting_from(self, index)
+    }
+
+    /// Find the first index not occupied by an item.
+    ///
+    /// If every index is occupied the index is resized, and the first index
+    /// past the previous capacity is returned.
+    #[inline]
+    pub(crate) fn next_unoccupied(&mut self) -> usize {
+        match self.unoccupied().next() {
+            Some(index) => index,
+            None => {
+                let capacity = self.capacity();
+                self.resize(capacity * 2);
+                capacity
+            }
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -193,6 +290,14 @@ impl<'a> Iterator for Occupied<'a> {
             OccupiedInner::BitArray(ref mut vec) => vec.next(),
         }
     }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        match self.0 {
+            OccupiedInner::BitVec(ref mut vec) => vec.nth(n),
+            OccupiedInner::BitArray(ref mut vec) => vec.nth(n),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -218,6 +323,20 @@ impl<'a> UnOccupied<'a> {
             }
         }
     }
+
+    #[inline]
+    fn starting_from(bit_tree: &'a Indexer, index: usize) -> Self {
+        match bit_tree.inner {
+            Inner::BitVec(ref vec) => {
+                let unoccupied = vec.unoccupied_from(index);
+                Self(UnOccupiedInner::BitVec(unoccupied))
+            }
+            Inner::BitArray(ref vec) => {
+                let unoccupied = vec.unoccupied_from(index);
+                Self(UnOccupiedInner::BitArray(unoccupied))
+            }
+        }
+    }
 }
 
 impl<'a> Iterator for UnOccupied<'a> {
@@ -227,10 +346,15 @@ impl<'a> Iterator for UnOccupied<'a> {
     fn next(&mut self) -> Option<Self::Item> {
         match self.0 {
             UnOccupiedInner::BitVec(ref mut vec) => vec.next(),
-            UnOccupiedInner::BitArray(ref mut vec) => match vec.next() {
-                Some(index) => Some(index),
-                None => Some(u64::BITS as usize * CAPACITY),
-            },
+            UnOccupiedInner::BitArray(ref mut vec) => vec.next(),
+        }
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        match self.0 {
+            UnOccupiedInner::BitVec(ref mut vec) => vec.nth(n),
+            UnOccupiedInner::BitArray(ref mut vec) => vec.nth(n),
         }
     }
 }
@@ -312,4 +436,97 @@ mod test {
         assert!(indexer.contains(0));
         assert!(indexer.contains(2));
     }
+
+    #[test]
+    fn next_unoccupied() {
+        let mut indexer = Indexer::new();
+        let capacity = indexer.capacity();
+        for n in 0..capacity {
+            assert_eq!(indexer.next_unoccupied(), n);
+            indexer.insert(n);
+        }
+
+        assert_eq!(indexer.unoccupied().next(), None);
+        assert_eq!(indexer.next_unoccupied(), capacity);
+        assert!(indexer.capacity() > capacity);
+        assert_eq!(indexer.len(), capacity);
+    }
+
+    #[test]
+    fn unoccupied_from() {
+        let mut indexer = Indexer::with_capacity(256);
+        for n in 0..200 {
+            indexer.insert(n);
+        }
+        indexer.remove(70);
+
+        let mut unoccupied = indexer.unoccupied_from(10);
+        assert_eq!(unoccupied.next(), Some(70));
+        assert_eq!(unoccupied.next(), Some(200));
+        assert_eq!(unoccupied.next(), Some(201));
+        assert_eq!(indexer.unoccupied_from(71).next(), Some(200));
+        assert_eq!(indexer.unoccupied_from(250).count(), 6);
+        assert_eq!(indexer.unoccupied_from(256).next(), None);
+        assert_eq!(indexer.unoccupied_from(0).count(), 57);
+    }
+
+    #[test]
+    fn shrink_to_fit() {
+        let mut indexer = Indexer::with_capacity(1024);
+        indexer.insert(3);
+        indexer.insert(70);
+        indexer.shrink_to_fit();
+        assert_eq!(indexer.capacity(), 128);
+        assert!(indexer.contains(3));
+        assert!(indexer.contains(70));
+        assert_eq!(indexer.len(), 2);
+    }
+
+    #[test]
+    fn next_occupied() {
+        let mut indexer = Indexer::with_capacity(256);
+        indexer.insert(3);
+        indexer.insert(130);
+        assert_eq!(indexer.next_occupied(0), Some(3));
+        assert_eq!(indexer.next_occupied(3), Some(3));
+        assert_eq!(indexer.next_occupied(4), Some(130));
+        assert_eq!(indexer.next_occupied(131), None);
+        assert_eq!(indexer.next_occupied(10_000), None);
+    }
+
+    #[test]
+    fn first_last_occupied() {
+        let mut indexer = Indexer::with_capacity(256);
+        assert_eq!(indexer.first_occupied(), None);
+        assert_eq!(indexer.last_occupied(), None);
+
+        indexer.insert(70);
+        indexer.insert(130);
+        assert_eq!(indexer.first_occupied(), Some(70));
+        assert_eq!(indexer.last_occupied(), Some(130));
+    }
+
+    #[test]
+    fn nth() {
+        let mut indexer = Indexer::with_capacity(1024);
+        for n in (0..300).step_by(3) {
+            indexer.insert(n);
+        }
+
+        assert_eq!(indexer.occupied().nth(50), Some(150));
+        assert_eq!(indexer.occupied().nth(99), Some(297));
+        assert_eq!(indexer.occupied().nth(100), None);
+
+        let mut occupied = indexer.occupied();
+        assert_eq!(occupied.nth(21), Some(63));
+        assert_eq!(occupied.nth(1), Some(69));
+        assert_eq!(occupied.next(), Some(72));
+
+        assert_eq!(indexer.unoccupied().nth(3), Some(5));
+        assert_eq!(indexer.unoccupied().nth(200), Some(300));
+
+        let mut unoccupied = indexer.unoccupied();
+        assert_eq!(unoccupied.nth(41), Some(62));
+        assert_eq!(unoccupied.nth(1), Some(65));
+    }
 }
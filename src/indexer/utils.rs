@@ -8,9 +8,57 @@ pub(crate) const fn compute_index(index: usize) -> (usize, usize) {
     (byte_position, bit_mask)
 }
 
+/// Find the position of the `n`th set bit in `word`, counting from the least
+/// significant bit. The caller must ensure that `word` has more than `n` bits
+/// set.
+#[inline]
+pub(crate) fn select_bit(mut word: usize, n: usize) -> usize {
+    debug_assert!((word.count_ones() as usize) > n);
+    for _ in 0..n {
+        // Clear the lowest set bit.
+        word &= word - 1;
+    }
+    word.trailing_zeros() as usize
+}
+
+/// Count how many bits are set in `words` before the bit at `index`.
+#[inline]
+pub(crate) fn rank(words: &[usize], index: usize) -> usize {
+    let (word_index, mask) = compute_index(index);
+    let full: usize = words
+        .iter()
+        .take(word_index)
+        .map(|word| word.count_ones() as usize)
+        .sum();
+    let partial = match words.get(word_index) {
+        Some(word) => (word & (mask - 1)).count_ones() as usize,
+        None => 0,
+    };
+    full + partial
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+
+    #[test]
+    fn rank_words() {
+        let words = [0b1011, usize::MAX];
+        assert_eq!(rank(&words, 0), 0);
+        assert_eq!(rank(&words, 1), 1);
+        assert_eq!(rank(&words, 4), 3);
+        assert_eq!(rank(&words, 64), 3);
+        assert_eq!(rank(&words, 70), 9);
+        assert_eq!(rank(&words, 1000), 67);
+    }
+
+    #[test]
+    fn select() {
+        assert_eq!(select_bit(0b00001, 0), 0);
+        assert_eq!(select_bit(0b10100, 0), 2);
+        assert_eq!(select_bit(0b10100, 1), 4);
+        assert_eq!(select_bit(usize::MAX, 63), 63);
+    }
     #[test]
     fn index() {
         assert_eq!(compute_index(0), (0, 0b00001));
@@ -0,0 +1,85 @@
+use std::fmt;
+
+use crate::{Key, Slab};
+
+/// A draining iterator over the items in the `Slab` which match a predicate.
+///
+/// When dropped, any remaining matching items are removed from the `Slab`.
+pub struct DrainWhere<'a, T, F>
+where
+    F: FnMut(Key, &mut T) -> bool,
+{
+    slab: &'a mut Slab<T>,
+    pred: F,
+    /// What is the next index to inspect?
+    cursor: usize,
+}
+
+impl<'a, T, F> DrainWhere<'a, T, F>
+where
+    F: FnMut(Key, &mut T) -> bool,
+{
+    pub(crate) fn new(slab: &'a mut Slab<T>, pred: F) -> Self {
+        Self {
+            slab,
+            pred,
+            cursor: 0,
+        }
+    }
+}
+
+impl<'a, T, F> fmt::Debug for DrainWhere<'a, T, F>
+where
+    F: FnMut(Key, &mut T) -> bool,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DrainWhere")
+            .field("cursor", &self.cursor)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, T, F> Iterator for DrainWhere<'a, T, F>
+where
+    F: FnMut(Key, &mut T) -> bool,
+{
+    type Item = (Key, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(index) = self.slab.index.next_occupied(self.cursor) {
+            self.cursor = index + 1;
+            let key = Key::new(index);
+            let value = self.slab.get_mut(key)?;
+            if (self.pred)(key, value) {
+                return self.slab.remove_at(index).map(|value| (key, value));
+            }
+        }
+        None
+    }
+}
+
+impl<'a, T, F> Drop for DrainWhere<'a, T, F>
+where
+    F: FnMut(Key, &mut T) -> bool,
+{
+    fn drop(&mut self) {
+        self.for_each(drop);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn iter() {
+        let mut slab: crate::Slab<usize> = (0..5).collect();
+        let mut iter = DrainWhere::new(&mut slab, |_, value| *value % 2 == 0);
+        assert_eq!(iter.next(), Some((0.into(), 0)));
+        assert_eq!(iter.next(), Some((2.into(), 2)));
+        drop(iter);
+
+        let pairs: Vec<_> = slab.into_iter().collect();
+        assert_eq!(pairs, vec![(1.into(), 1), (3.into(), 3)]);
+    }
+}
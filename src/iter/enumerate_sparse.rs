@@ -0,0 +1,59 @@
+use crate::Slab;
+
+/// An iterator over all slots in the `Slab`, both occupied and vacant.
+#[derive(Debug)]
+pub struct EnumerateSparse<'a, T> {
+    slab: &'a Slab<T>,
+    /// What is the current index of the cursor?
+    cursor: usize,
+    /// One past the last occupied index.
+    end: usize,
+}
+
+impl<'a, T> EnumerateSparse<'a, T> {
+    pub(crate) fn new(slab: &'a Slab<T>) -> Self {
+        let end = slab.max_occupied_key().map_or(0, |key| key.index() + 1);
+        Self {
+            slab,
+            cursor: 0,
+            end,
+        }
+    }
+}
+
+impl<'a, T> Iterator for EnumerateSparse<'a, T> {
+    type Item = (usize, Option<&'a T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.cursor == self.end {
+            return None;
+        }
+        let index = self.cursor;
+        self.cursor += 1;
+        Some((index, self.slab.get(index.into())))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.cursor;
+        (len, Some(len))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn iter() {
+        let mut slab = crate::Slab::new();
+        slab.insert(1);
+        let key = slab.insert(2);
+        slab.insert(3);
+        slab.remove(key);
+        let mut iter = EnumerateSparse::new(&slab);
+        assert_eq!(iter.next(), Some((0, Some(&1))));
+        assert_eq!(iter.next(), Some((1, None)));
+        assert_eq!(iter.next(), Some((2, Some(&3))));
+        assert_eq!(iter.next(), None);
+    }
+}
@@ -0,0 +1,60 @@
+use std::array;
+use std::collections::VecDeque;
+
+use crate::{Iter, Key, Slab};
+
+/// An iterator over overlapping windows of `N` consecutive items in the `Slab`.
+#[derive(Debug)]
+pub struct EnumeratedWindows<'a, T, const N: usize> {
+    iter: Iter<'a, T>,
+    window: VecDeque<(Key, &'a T)>,
+}
+
+impl<'a, T, const N: usize> EnumeratedWindows<'a, T, N> {
+    pub(crate) fn new(slab: &'a Slab<T>) -> Self {
+        assert!(N != 0, "window size must be non-zero");
+        Self {
+            iter: slab.iter(),
+            window: VecDeque::with_capacity(N),
+        }
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for EnumeratedWindows<'a, T, N> {
+    type Item = ([Key; N], [&'a T; N]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Slide the window forward by one, filling it up on the first call.
+        if self.window.len() == N {
+            self.window.pop_front();
+        }
+        while self.window.len() < N {
+            self.window.push_back(self.iter.next()?);
+        }
+        let keys = array::from_fn(|i| self.window[i].0);
+        let values = array::from_fn(|i| self.window[i].1);
+        Some((keys, values))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn iter() {
+        let mut slab = crate::Slab::new();
+        slab.insert(1);
+        let key = slab.insert(2);
+        slab.insert(3);
+        slab.insert(4);
+        slab.remove(key);
+        let mut iter = EnumeratedWindows::<_, 2>::new(&slab);
+        assert_eq!(iter.next(), Some(([0.into(), 2.into()], [&1, &3])));
+        assert_eq!(iter.next(), Some(([2.into(), 3.into()], [&3, &4])));
+        assert_eq!(iter.next(), None);
+
+        let mut iter = EnumeratedWindows::<_, 4>::new(&slab);
+        assert_eq!(iter.next(), None);
+    }
+}
@@ -0,0 +1,40 @@
+use crate::IntoIter;
+
+/// An owned iterator over items in the `Slab`, with plain `usize` keys.
+#[derive(Debug)]
+pub struct IntoKeyValues<T> {
+    iter: IntoIter<T>,
+}
+
+impl<T> IntoKeyValues<T> {
+    pub(crate) fn new(slab: crate::Slab<T>) -> Self {
+        let iter = IntoIter::new(slab);
+        Self { iter }
+    }
+}
+
+impl<T> Iterator for IntoKeyValues<T> {
+    type Item = (usize, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(key, value)| (key.into(), value))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn into_iter() {
+        let mut slab = crate::Slab::new();
+        slab.insert(1);
+        let key = slab.insert(2);
+        slab.insert(3);
+        slab.remove(key);
+        let mut iter = IntoKeyValues::new(slab);
+        assert_eq!(iter.next(), Some((0, 1)));
+        assert_eq!(iter.next(), Some((2, 3)));
+        assert_eq!(iter.next(), None);
+    }
+}
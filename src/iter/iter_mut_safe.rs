@@ -0,0 +1,68 @@
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+
+use crate::indexer::Occupied;
+use crate::{Key, Slab};
+
+/// A mutable iterator over items in the `Slab` which indexes directly into
+/// the entries, rather than stepping over every vacant slot.
+#[derive(Debug)]
+pub struct IterMutSafe<'a, T> {
+    occupied: Occupied<'a>,
+    entries: *mut MaybeUninit<T>,
+    len: usize,
+    _marker: PhantomData<&'a mut T>,
+}
+
+// SAFETY: `IterMutSafe` behaves like a `&mut [T]`, so it can be sent and
+// shared under the same conditions.
+unsafe impl<'a, T: Send> Send for IterMutSafe<'a, T> {}
+unsafe impl<'a, T: Sync> Sync for IterMutSafe<'a, T> {}
+
+impl<'a, T> IterMutSafe<'a, T> {
+    pub(crate) fn new(slab: &'a mut Slab<T>) -> Self {
+        let occupied = slab.index.occupied();
+        let len = slab.entries.len();
+        let entries = slab.entries.as_mut_ptr();
+        Self {
+            occupied,
+            entries,
+            len,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> Iterator for IterMutSafe<'a, T> {
+    type Item = (Key, &'a mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.occupied.next()?;
+        if index >= self.len {
+            return None;
+        }
+        // SAFETY: `index` is in bounds and marked as occupied, so the entry is
+        // initialized. The occupied indexes are strictly increasing, so no
+        // entry is handed out twice.
+        let value = unsafe { (*self.entries.add(index)).assume_init_mut() };
+        Some((index.into(), value))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn iter() {
+        let mut slab = crate::Slab::new();
+        slab.insert(1);
+        let key = slab.insert(2);
+        slab.insert(3);
+        slab.remove(key);
+        let mut iter = IterMutSafe::new(&mut slab);
+        assert_eq!(iter.next(), Some((0.into(), &mut 1)));
+        assert_eq!(iter.next(), Some((2.into(), &mut 3)));
+        assert_eq!(iter.next(), None);
+    }
+}
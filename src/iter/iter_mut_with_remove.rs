@@ -0,0 +1,64 @@
+use crate::{Key, Slab};
+
+/// A mutable cursor over items in the `Slab` which can remove the item it
+/// last yielded.
+///
+/// This is not an [`Iterator`]: each item borrows the cursor, so it must be
+/// released before calling [`IterMutWithRemove::remove_current`].
+#[derive(Debug)]
+pub struct IterMutWithRemove<'a, T> {
+    slab: &'a mut Slab<T>,
+    /// What is the next index to inspect?
+    cursor: usize,
+    /// Which key was last yielded, if it has not been removed yet?
+    current: Option<Key>,
+}
+
+impl<'a, T> IterMutWithRemove<'a, T> {
+    pub(crate) fn new(slab: &'a mut Slab<T>) -> Self {
+        Self {
+            slab,
+            cursor: 0,
+            current: None,
+        }
+    }
+
+    /// Advances the cursor, and returns the next key-value pair.
+    // This can't implement `Iterator`: the item borrows from the cursor.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<(Key, &mut T)> {
+        let index = self.slab.index.next_occupied(self.cursor)?;
+        self.cursor = index + 1;
+        let key = Key::new(index);
+        self.current = Some(key);
+        self.slab.get_mut(key).map(|value| (key, value))
+    }
+
+    /// Removes the entry which was last yielded, and returns its value.
+    ///
+    /// Returns `None` if no entry has been yielded yet, or if it was already
+    /// removed.
+    pub fn remove_current(&mut self) -> Option<T> {
+        let key = self.current.take()?;
+        self.slab.remove_at(key.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn iter() {
+        let mut slab: crate::Slab<usize> = (0..3).collect();
+        let mut iter = IterMutWithRemove::new(&mut slab);
+        assert_eq!(iter.remove_current(), None);
+        assert_eq!(iter.next(), Some((0.into(), &mut 0)));
+        assert_eq!(iter.next(), Some((1.into(), &mut 1)));
+        assert_eq!(iter.remove_current(), Some(1));
+        assert_eq!(iter.remove_current(), None);
+        assert_eq!(iter.next(), Some((2.into(), &mut 2)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(slab.len(), 2);
+    }
+}
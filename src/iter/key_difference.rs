@@ -0,0 +1,60 @@
+use crate::{Key, Slab};
+
+/// An iterator over the keys occupied in one `Slab` but not in another.
+#[derive(Debug)]
+pub struct KeyDifference<'a> {
+    lhs: &'a [usize],
+    rhs: &'a [usize],
+    /// What is the index of the next word to load?
+    cursor: usize,
+    /// The remaining bits of the current word.
+    word: usize,
+    /// What is the index of the current word?
+    word_index: usize,
+}
+
+impl<'a> KeyDifference<'a> {
+    pub(crate) fn new<T, U>(lhs: &'a Slab<T>, rhs: &'a Slab<U>) -> Self {
+        Self {
+            lhs: lhs.index.words(),
+            rhs: rhs.index.words(),
+            cursor: 0,
+            word: 0,
+            word_index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for KeyDifference<'a> {
+    type Item = Key;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.word == 0 {
+            let lhs = *self.lhs.get(self.cursor)?;
+            let rhs = self.rhs.get(self.cursor).copied().unwrap_or(0);
+            self.word = lhs & !rhs;
+            self.word_index = self.cursor;
+            self.cursor += 1;
+        }
+        let bit = self.word.trailing_zeros() as usize;
+        // Clear the lowest set bit.
+        self.word &= self.word - 1;
+        Some(Key::new(self.word_index * usize::BITS as usize + bit))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn iter() {
+        let lhs: crate::Slab<usize> = (0..200).collect();
+        let mut rhs: crate::Slab<usize> = (0..199).collect();
+        rhs.remove(1.into());
+        let mut iter = KeyDifference::new(&lhs, &rhs);
+        assert_eq!(iter.next(), Some(1.into()));
+        assert_eq!(iter.next(), Some(199.into()));
+        assert_eq!(iter.next(), None);
+    }
+}
@@ -0,0 +1,83 @@
+use std::fmt;
+
+use crate::{Iter, Key, Slab};
+
+/// An iterator over runs of key-value pairs in the `Slab` which belong to the
+/// same group.
+pub struct KeyGroups<'a, T, F>
+where
+    F: Fn(Key) -> usize,
+{
+    iter: Iter<'a, T>,
+    group_fn: F,
+    /// The first pair of the next group, if we've already read it.
+    peeked: Option<(usize, (Key, &'a T))>,
+}
+
+impl<'a, T, F> KeyGroups<'a, T, F>
+where
+    F: Fn(Key) -> usize,
+{
+    pub(crate) fn new(slab: &'a Slab<T>, group_fn: F) -> Self {
+        Self {
+            iter: slab.iter(),
+            group_fn,
+            peeked: None,
+        }
+    }
+}
+
+impl<'a, T, F> fmt::Debug for KeyGroups<'a, T, F>
+where
+    T: fmt::Debug,
+    F: Fn(Key) -> usize,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeyGroups")
+            .field("iter", &self.iter)
+            .field("peeked", &self.peeked)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, T, F> Iterator for KeyGroups<'a, T, F>
+where
+    F: Fn(Key) -> usize,
+{
+    type Item = (usize, Vec<(Key, &'a T)>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (group, first) = match self.peeked.take() {
+            Some(peeked) => peeked,
+            None => {
+                let (key, value) = self.iter.next()?;
+                ((self.group_fn)(key), (key, value))
+            }
+        };
+        let mut pairs = vec![first];
+        for (key, value) in self.iter.by_ref() {
+            let next_group = (self.group_fn)(key);
+            if next_group != group {
+                self.peeked = Some((next_group, (key, value)));
+                break;
+            }
+            pairs.push((key, value));
+        }
+        Some((group, pairs))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn iter() {
+        let slab: crate::Slab<usize> = (0..5).collect();
+        let mut iter = KeyGroups::new(&slab, |key| usize::from(key) / 2);
+        assert_eq!(iter.next(), Some((0, vec![(0.into(), &0), (1.into(), &1)])));
+        assert_eq!(iter.next(), Some((1, vec![(2.into(), &2), (3.into(), &3)])));
+        assert_eq!(iter.next(), Some((2, vec![(4.into(), &4)])));
+        assert_eq!(iter.next(), None);
+    }
+}
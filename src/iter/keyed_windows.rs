@@ -0,0 +1,45 @@
+use std::array;
+
+use crate::{EnumeratedWindows, Key, Slab};
+
+/// An iterator over overlapping windows of `N` consecutive key-value pairs in
+/// the `Slab`.
+#[derive(Debug)]
+pub struct KeyedWindows<'a, T, const N: usize> {
+    windows: EnumeratedWindows<'a, T, N>,
+}
+
+impl<'a, T, const N: usize> KeyedWindows<'a, T, N> {
+    pub(crate) fn new(slab: &'a Slab<T>) -> Self {
+        let windows = EnumeratedWindows::new(slab);
+        Self { windows }
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for KeyedWindows<'a, T, N> {
+    type Item = [(Key, &'a T); N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (keys, values) = self.windows.next()?;
+        Some(array::from_fn(|i| (keys[i], values[i])))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn iter() {
+        let mut slab = crate::Slab::new();
+        slab.insert(1);
+        let key = slab.insert(2);
+        slab.insert(3);
+        slab.insert(4);
+        slab.remove(key);
+        let mut iter = KeyedWindows::<_, 2>::new(&slab);
+        assert_eq!(iter.next(), Some([(0.into(), &1), (2.into(), &3)]));
+        assert_eq!(iter.next(), Some([(2.into(), &3), (3.into(), &4)]));
+        assert_eq!(iter.next(), None);
+    }
+}
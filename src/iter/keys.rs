@@ -20,6 +20,10 @@ impl<'a> Iterator for Keys<'a> {
     fn next(&mut self) -> Option<Self::Item> {
         self.occupied.next().map(|index| index.into())
     }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.occupied.nth(n).map(|index| index.into())
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,47 @@
+use crate::{Key, Keys, Slab};
+
+/// An iterator over chunks of keys in the `Slab`.
+#[derive(Debug)]
+pub struct KeysChunks<'a> {
+    keys: Keys<'a>,
+    chunk_size: usize,
+}
+
+impl<'a> KeysChunks<'a> {
+    pub(crate) fn new<T>(slab: &'a Slab<T>, chunk_size: usize) -> Self {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+        let keys = slab.keys();
+        Self { keys, chunk_size }
+    }
+}
+
+impl<'a> Iterator for KeysChunks<'a> {
+    type Item = Vec<Key>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let chunk: Vec<Key> = self.keys.by_ref().take(self.chunk_size).collect();
+        match chunk.is_empty() {
+            true => None,
+            false => Some(chunk),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn iter() {
+        let mut slab = crate::Slab::new();
+        slab.insert(1);
+        let key = slab.insert(2);
+        slab.insert(3);
+        slab.insert(4);
+        slab.remove(key);
+        let mut iter = KeysChunks::new(&slab, 2);
+        assert_eq!(iter.next(), Some(vec![0.into(), 2.into()]));
+        assert_eq!(iter.next(), Some(vec![3.into()]));
+        assert_eq!(iter.next(), None);
+    }
+}
@@ -2,19 +2,47 @@ mod into_iter;
 #[allow(clippy::module_inception)]
 mod iter;
 mod iter_mut;
+mod iter_mut_safe;
+mod iter_mut_with_remove;
 
+mod into_key_values;
 mod into_values;
 mod values;
 mod values_mut;
 
+mod drain_where;
+mod enumerate_sparse;
+mod enumerated_windows;
+mod key_difference;
+mod key_groups;
+mod keyed_windows;
 mod keys;
+mod keys_chunks;
+mod occupied_chunks;
+mod occupied_words;
+mod pages;
+mod positions;
 
 pub use into_iter::IntoIter;
 pub use iter::Iter;
 pub use iter_mut::IterMut;
+pub use iter_mut_safe::IterMutSafe;
+pub use iter_mut_with_remove::IterMutWithRemove;
 
+pub use into_key_values::IntoKeyValues;
 pub use into_values::IntoValues;
 pub use values::Values;
 pub use values_mut::ValuesMut;
 
+pub use drain_where::DrainWhere;
+pub use enumerate_sparse::EnumerateSparse;
+pub use enumerated_windows::EnumeratedWindows;
+pub use key_difference::KeyDifference;
+pub use key_groups::KeyGroups;
+pub use keyed_windows::KeyedWindows;
 pub use keys::Keys;
+pub use keys_chunks::KeysChunks;
+pub use occupied_chunks::{ContiguousBlock, OccupiedChunks};
+pub use occupied_words::OccupiedWords;
+pub use pages::{Pages, SlabPage};
+pub use positions::Positions;
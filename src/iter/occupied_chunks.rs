@@ -0,0 +1,79 @@
+use crate::{Key, Slab};
+
+/// A maximal run of consecutive occupied keys in the `Slab`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContiguousBlock {
+    /// The first key of the run.
+    pub start: Key,
+    /// The number of keys in the run.
+    pub len: usize,
+}
+
+/// An iterator over the runs of consecutive occupied keys in the `Slab`.
+#[derive(Debug)]
+pub struct OccupiedChunks<'a> {
+    words: &'a [usize],
+    /// What is the next index to inspect?
+    cursor: usize,
+}
+
+impl<'a> OccupiedChunks<'a> {
+    pub(crate) fn new<T>(slab: &'a Slab<T>) -> Self {
+        let words = slab.index.words();
+        Self { words, cursor: 0 }
+    }
+
+    /// Find the first index at or after the cursor whose bit equals `occupied`.
+    fn find_bit(&self, occupied: bool) -> Option<usize> {
+        let bits = usize::BITS as usize;
+        let mut word_index = self.cursor / bits;
+        let offset = self.cursor % bits;
+        let flip = |word: usize| if occupied { word } else { !word };
+        let mut word = flip(*self.words.get(word_index)?) & (usize::MAX << offset);
+        while word == 0 {
+            word_index += 1;
+            word = flip(*self.words.get(word_index)?);
+        }
+        Some(word_index * bits + word.trailing_zeros() as usize)
+    }
+}
+
+impl<'a> Iterator for OccupiedChunks<'a> {
+    type Item = ContiguousBlock;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.find_bit(true)?;
+        self.cursor = start;
+        let end = self
+            .find_bit(false)
+            .unwrap_or(self.words.len() * usize::BITS as usize);
+        self.cursor = end;
+        Some(ContiguousBlock {
+            start: Key::new(start),
+            len: end - start,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn iter() {
+        let mut slab: crate::Slab<usize> = (0..128).collect();
+        for key in 2..100 {
+            slab.remove(key.into());
+        }
+        slab.remove(110.into());
+        let mut iter = OccupiedChunks::new(&slab);
+        let block = |start: usize, len| ContiguousBlock {
+            start: start.into(),
+            len,
+        };
+        assert_eq!(iter.next(), Some(block(0, 2)));
+        assert_eq!(iter.next(), Some(block(100, 10)));
+        assert_eq!(iter.next(), Some(block(111, 17)));
+        assert_eq!(iter.next(), None);
+    }
+}
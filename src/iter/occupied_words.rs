@@ -0,0 +1,48 @@
+use crate::Slab;
+
+/// An iterator over the non-empty words of the `Slab`'s index.
+#[derive(Debug)]
+pub struct OccupiedWords<'a> {
+    words: &'a [usize],
+    /// What is the current index of the cursor?
+    cursor: usize,
+}
+
+impl<'a> OccupiedWords<'a> {
+    pub(crate) fn new<T>(slab: &'a Slab<T>) -> Self {
+        let words = slab.index.words();
+        Self { words, cursor: 0 }
+    }
+}
+
+impl<'a> Iterator for OccupiedWords<'a> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(&word) = self.words.get(self.cursor) {
+            let index = self.cursor;
+            self.cursor += 1;
+            if word != 0 {
+                return Some((index, word));
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn iter() {
+        let mut slab = crate::Slab::with_capacity(256);
+        slab.insert_hint(1.into(), 1);
+        slab.insert_hint(130.into(), 2);
+        slab.insert_hint(131.into(), 3);
+        let mut iter = OccupiedWords::new(&slab);
+        assert_eq!(iter.next(), Some((0, 0b10)));
+        assert_eq!(iter.next(), Some((2, 0b1100)));
+        assert_eq!(iter.next(), None);
+    }
+}
@@ -0,0 +1,106 @@
+use std::ops::Range;
+
+use crate::{Key, Slab};
+
+/// An iterator over fixed-size pages of keys in the `Slab`.
+#[derive(Debug)]
+pub struct Pages<'a, T> {
+    slab: &'a Slab<T>,
+    page_size: usize,
+    /// What is the first key of the next page?
+    cursor: usize,
+}
+
+impl<'a, T> Pages<'a, T> {
+    pub(crate) fn new(slab: &'a Slab<T>, page_size: usize) -> Self {
+        assert!(page_size != 0, "page size must be non-zero");
+        Self {
+            slab,
+            page_size,
+            cursor: 0,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Pages<'a, T> {
+    type Item = SlabPage<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let capacity = self.slab.capacity();
+        if self.cursor >= capacity {
+            return None;
+        }
+        let start = self.cursor;
+        let end = start.saturating_add(self.page_size).min(capacity);
+        self.cursor = end;
+        Some(SlabPage::new(self.slab, start..end))
+    }
+}
+
+/// A page of keys in the `Slab`, which iterates over the occupied entries
+/// within it.
+#[derive(Debug)]
+pub struct SlabPage<'a, T> {
+    slab: &'a Slab<T>,
+    range: Range<usize>,
+    /// What is the next index to inspect?
+    cursor: usize,
+}
+
+impl<'a, T> SlabPage<'a, T> {
+    fn new(slab: &'a Slab<T>, range: Range<usize>) -> Self {
+        let cursor = range.start;
+        Self {
+            slab,
+            range,
+            cursor,
+        }
+    }
+
+    /// Returns the range of key indexes covered by this page.
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+}
+
+impl<'a, T> Iterator for SlabPage<'a, T> {
+    type Item = (Key, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.slab.index.next_occupied(self.cursor)?;
+        if index >= self.range.end {
+            self.cursor = self.range.end;
+            return None;
+        }
+        self.cursor = index + 1;
+        let key = Key::new(index);
+        self.slab.get(key).map(|value| (key, value))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn iter() {
+        let mut slab: crate::Slab<usize> = (0..100).collect();
+        slab.retain(|key, _| key.index() % 30 == 0);
+        let mut iter = Pages::new(&slab, 50);
+
+        let mut page = iter.next().unwrap();
+        assert_eq!(page.range(), 0..50);
+        assert_eq!(page.next(), Some((0.into(), &0)));
+        assert_eq!(page.next(), Some((30.into(), &30)));
+        assert_eq!(page.next(), None);
+
+        let page = iter.next().unwrap();
+        assert_eq!(page.range(), 50..100);
+        assert_eq!(page.count(), 2);
+
+        let page = iter.next().unwrap();
+        assert_eq!(page.range(), 100..128);
+        assert_eq!(page.count(), 0);
+        assert!(iter.next().is_none());
+    }
+}
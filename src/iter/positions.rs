@@ -0,0 +1,63 @@
+use std::fmt;
+
+use crate::{Iter, Key, Slab};
+
+/// An iterator over the keys of the items in the `Slab` which match a
+/// predicate.
+pub struct Positions<'a, T, F>
+where
+    F: Fn(&T) -> bool,
+{
+    iter: Iter<'a, T>,
+    pred: F,
+}
+
+impl<'a, T, F> Positions<'a, T, F>
+where
+    F: Fn(&T) -> bool,
+{
+    pub(crate) fn new(slab: &'a Slab<T>, pred: F) -> Self {
+        let iter = slab.iter();
+        Self { iter, pred }
+    }
+}
+
+impl<'a, T, F> fmt::Debug for Positions<'a, T, F>
+where
+    T: fmt::Debug,
+    F: Fn(&T) -> bool,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Positions")
+            .field("iter", &self.iter)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, T, F> Iterator for Positions<'a, T, F>
+where
+    F: Fn(&T) -> bool,
+{
+    type Item = Key;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let pred = &self.pred;
+        self.iter
+            .by_ref()
+            .find_map(|(key, value)| pred(value).then_some(key))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn iter() {
+        let slab: crate::Slab<usize> = (0..5).collect();
+        let mut iter = Positions::new(&slab, |value| *value >= 3);
+        assert_eq!(iter.next(), Some(3.into()));
+        assert_eq!(iter.next(), Some(4.into()));
+        assert_eq!(iter.next(), None);
+    }
+}
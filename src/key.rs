@@ -1,11 +1,51 @@
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
 /// An key into the [`Slab`](crate::Slab) structure.
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
 pub struct Key(usize);
 
 impl Key {
-    pub(crate) fn new(index: usize) -> Key {
+    /// Creates a new key from an index.
+    #[inline(always)]
+    pub const fn new(index: usize) -> Key {
         Self(index)
     }
+
+    /// Returns the index of the key.
+    #[inline(always)]
+    pub const fn index(self) -> usize {
+        self.0
+    }
+
+    /// Offsets the key by `rhs`, returning `None` on overflow.
+    #[inline(always)]
+    pub const fn checked_add(self, rhs: usize) -> Option<Key> {
+        match self.0.checked_add(rhs) {
+            Some(index) => Some(Self(index)),
+            None => None,
+        }
+    }
+
+    /// Offsets the key by `-rhs`, returning `None` on underflow.
+    #[inline(always)]
+    pub const fn checked_sub(self, rhs: usize) -> Option<Key> {
+        match self.0.checked_sub(rhs) {
+            Some(index) => Some(Self(index)),
+            None => None,
+        }
+    }
+
+    /// Offsets the key by `rhs`, wrapping around at `usize::MAX`.
+    #[inline(always)]
+    pub const fn wrapping_add(self, rhs: usize) -> Key {
+        Self(self.0.wrapping_add(rhs))
+    }
+
+    /// Offsets the key by `-rhs`, wrapping around at zero.
+    #[inline(always)]
+    pub const fn wrapping_sub(self, rhs: usize) -> Key {
+        Self(self.0.wrapping_sub(rhs))
+    }
 }
 
 impl From<Key> for usize {
@@ -21,3 +61,103 @@ impl From<usize> for Key {
         Self(value)
     }
 }
+
+/// Offsets the key by `rhs`.
+///
+/// # Panics
+///
+/// Panics on overflow, in both debug and release builds. Use
+/// [`Key::checked_add`] or [`Key::wrapping_add`] to handle overflow instead.
+impl Add<usize> for Key {
+    type Output = Key;
+
+    #[inline(always)]
+    fn add(self, rhs: usize) -> Self::Output {
+        self.checked_add(rhs).expect("key overflowed")
+    }
+}
+
+/// Offsets the key by `rhs`, panicking on overflow like [`Add`].
+impl AddAssign<usize> for Key {
+    #[inline(always)]
+    fn add_assign(&mut self, rhs: usize) {
+        *self = *self + rhs;
+    }
+}
+
+/// Offsets the key by `-rhs`.
+///
+/// # Panics
+///
+/// Panics on underflow, in both debug and release builds. Use
+/// [`Key::checked_sub`] or [`Key::wrapping_sub`] to handle underflow instead.
+impl Sub<usize> for Key {
+    type Output = Key;
+
+    #[inline(always)]
+    fn sub(self, rhs: usize) -> Self::Output {
+        self.checked_sub(rhs).expect("key underflowed")
+    }
+}
+
+/// Offsets the key by `-rhs`, panicking on underflow like [`Sub`].
+impl SubAssign<usize> for Key {
+    #[inline(always)]
+    fn sub_assign(&mut self, rhs: usize) {
+        *self = *self - rhs;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn constant() {
+        const KEY: Key = Key::new(42);
+        const INDEX: usize = KEY.index();
+        assert_eq!(KEY, Key::from(42));
+        assert_eq!(INDEX, 42);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let mut key = Key::new(4);
+        assert_eq!(key + 3, Key::new(7));
+        assert_eq!(key - 3, Key::new(1));
+        key += 2;
+        assert_eq!(key, Key::new(6));
+        key -= 6;
+        assert_eq!(key, Key::new(0));
+    }
+
+    #[test]
+    fn overflow() {
+        let max = Key::new(usize::MAX);
+        assert_eq!(max.checked_add(1), None);
+        assert_eq!(max.wrapping_add(2), Key::new(1));
+        assert_eq!(Key::new(0).checked_sub(1), None);
+        assert_eq!(Key::new(0).wrapping_sub(1), max);
+        assert_eq!(Key::new(3).checked_sub(1), Some(Key::new(2)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn add_overflow() {
+        let _ = Key::new(usize::MAX) + 1;
+    }
+
+    #[test]
+    #[should_panic]
+    fn sub_underflow() {
+        let mut key = Key::new(0);
+        key -= 1;
+    }
+
+    #[test]
+    fn hash() {
+        let keys: std::collections::HashSet<Key> = [1, 2, 1].into_iter().map(Key::new).collect();
+        assert_eq!(keys.len(), 2);
+        assert!(keys.contains(&Key::new(2)));
+    }
+}
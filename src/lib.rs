@@ -23,11 +23,28 @@
 #![deny(missing_debug_implementations, nonstandard_style)]
 #![warn(missing_docs, future_incompatible, unreachable_pub)]
 
+mod delta;
+mod error;
 mod indexer;
 mod iter;
 mod key;
 mod slab;
+#[cfg(feature = "stats")]
+mod stats;
+#[cfg(feature = "time")]
+mod time;
 
 pub use self::slab::Slab;
-pub use iter::{IntoIter, IntoValues, Iter, IterMut, Keys, Values, ValuesMut};
+pub use delta::SlabDelta;
+pub use error::{BatchInsertError, ShrinkError};
+pub use iter::{
+    ContiguousBlock, DrainWhere, EnumerateSparse, EnumeratedWindows, IntoIter, IntoKeyValues,
+    IntoValues, Iter, IterMut, IterMutSafe, IterMutWithRemove, KeyDifference, KeyGroups,
+    KeyedWindows, Keys, KeysChunks, OccupiedChunks, OccupiedWords, Pages, Positions, SlabPage,
+    Values, ValuesMut,
+};
 pub use key::Key;
+#[cfg(feature = "stats")]
+pub use stats::{SlabStats, StatsSlab};
+#[cfg(feature = "time")]
+pub use time::TimestampedSlab;
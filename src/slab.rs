@@ -1,19 +1,41 @@
 use crate::indexer::Indexer;
-use crate::{IntoIter, IntoValues, Iter, IterMut, Key, Keys, Values, ValuesMut};
+use crate::{BatchInsertError, ShrinkError, SlabDelta};
+use crate::{
+    DrainWhere, EnumerateSparse, EnumeratedWindows, IntoIter, IntoKeyValues, IntoValues, Iter,
+    IterMut, IterMutSafe, IterMutWithRemove, Key, KeyDifference, KeyGroups, KeyedWindows, Keys,
+    KeysChunks, OccupiedChunks, OccupiedWords, Pages, Positions, Values, ValuesMut,
+};
 
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
 use std::mem::{self, MaybeUninit};
-use std::ops::{Index, IndexMut};
+use std::ops::{Add, AddAssign, Bound, Index, IndexMut, RangeBounds};
+
+/// By how much should the slab grow when it runs out of capacity?
+const DEFAULT_GROW_FACTOR: f64 = 2.0;
 
 /// A slab allocator
-#[derive(Default)]
 pub struct Slab<T> {
     pub(crate) index: Indexer,
     pub(crate) entries: Vec<MaybeUninit<T>>,
+    grow_factor: f64,
+    max_tombstones: Option<usize>,
+    total_inserts: u64,
+}
+
+impl<T> Default for Slab<T> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<T: std::fmt::Debug> std::fmt::Debug for Slab<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("Slab").field("index", &self.index).finish()
+        f.write_str("Slab ")?;
+        f.debug_map()
+            .entries(self.iter().map(|(key, value)| (usize::from(key), value)))
+            .finish()
     }
 }
 
@@ -28,20 +50,263 @@ impl<T> Slab<T> {
         Self {
             index: Indexer::with_capacity(capacity),
             entries: Vec::with_capacity(capacity),
+            grow_factor: DEFAULT_GROW_FACTOR,
+            max_tombstones: None,
+            total_inserts: 0,
+        }
+    }
+
+    /// Creates an empty `Slab` with at least the specified capacity, which
+    /// grows by `factor` whenever it runs out of capacity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `factor` is not greater than 1.0.
+    pub fn with_grow_factor(capacity: usize, factor: f64) -> Self {
+        assert!(factor > 1.0, "grow factor must be greater than 1.0");
+        let mut slab = Self::with_capacity(capacity);
+        slab.grow_factor = factor;
+        slab
+    }
+
+    /// Creates an empty `Slab` which compacts itself once more than
+    /// `max_tombstones` of its slots are vacant.
+    ///
+    /// The limit is checked after every call to [`Slab::remove`], which then
+    /// calls [`Slab::compact`]. Compacting moves entries to new keys: the entry
+    /// with the n-th smallest key moves to key `n`. Keys obtained before a
+    /// `remove` may refer to a different entry afterwards.
+    pub fn with_tombstone_limit(max_tombstones: usize) -> Self {
+        let mut slab = Self::new();
+        slab.max_tombstones = Some(max_tombstones);
+        slab
+    }
+
+    /// Creates a `Slab` with the keys `0..len` all occupied by clones of
+    /// `default`.
+    pub fn with_default_value(len: usize, default: T) -> Self
+    where
+        T: Clone,
+    {
+        let mut slab = Self::with_capacity(len);
+        slab.entries
+            .resize_with(len, || MaybeUninit::new(default.clone()));
+        for index in 0..len {
+            slab.index.insert(index);
+        }
+        slab.total_inserts = len as u64;
+        slab
+    }
+
+    /// Creates a `Slab` from a bitset of occupied keys and their values.
+    ///
+    /// This is the inverse of [`Slab::keys_as_bitset`]: bit `key %
+    /// usize::BITS` of word `key / usize::BITS` marks `values[key]` as
+    /// occupied.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a key is marked as occupied which is out of bounds of
+    /// `values`.
+    ///
+    /// # Safety
+    ///
+    /// Every value marked as occupied in `bitset` must be initialized.
+    pub unsafe fn from_keys_bitset(bitset: Vec<usize>, values: Vec<MaybeUninit<T>>) -> Self {
+        let mut index = Indexer::with_capacity(values.len());
+        for (word_index, word) in bitset.into_iter().enumerate() {
+            let mut word = word;
+            while word != 0 {
+                let key = word_index * usize::BITS as usize + word.trailing_zeros() as usize;
+                assert!(key < values.len(), "key {key} is out of bounds");
+                index.insert(key);
+                // Clear the lowest set bit.
+                word &= word - 1;
+            }
+        }
+        let total_inserts = index.len() as u64;
+        Self {
+            index,
+            entries: values,
+            grow_factor: DEFAULT_GROW_FACTOR,
+            max_tombstones: None,
+            total_inserts,
         }
     }
 
     /// Clears the map, removing all key-value pairs. Keeps the allocated memory for reuse.
     pub fn clear(&mut self) {
+        for index in self.index.occupied() {
+            // SAFETY: we're going over all items marked as "occupied" and
+            // dropping them in-place.
+            unsafe { self.entries[index].assume_init_drop() }
+        }
         self.index.clear();
         self.entries.clear();
     }
 
+    /// Clears the map, removing all key-value pairs. Releases the allocated
+    /// memory.
+    pub fn clear_and_release(&mut self) {
+        self.clear();
+        self.index = Indexer::new();
+        self.entries.shrink_to_fit();
+    }
+
+    /// Overwrites the contents of `self` with those of `src`, keeping all keys.
+    ///
+    /// Reuses the existing allocation where possible. The capacity of `self`
+    /// will be at least its original capacity.
+    pub fn copy_from(&mut self, src: &Slab<T>)
+    where
+        T: Copy,
+    {
+        let capacity = self.capacity();
+        self.index.clone_from(&src.index);
+        if capacity > self.index.capacity() {
+            self.index.resize(capacity);
+        }
+        self.entries.clear();
+        self.entries.extend_from_slice(&src.entries);
+    }
+
+    /// Copies the values into `dst` from start to end, without gaps.
+    ///
+    /// Returns the number of values copied, which is the smaller of
+    /// `self.len()` and `dst.len()`.
+    pub fn copy_to_slice(&self, dst: &mut [T]) -> usize
+    where
+        T: Copy,
+    {
+        let mut count = 0;
+        for (dst, value) in dst.iter_mut().zip(self.values()) {
+            *dst = *value;
+            count += 1;
+        }
+        count
+    }
+
+    /// Maps every value with `f`, and packs the results into a `Vec` without
+    /// gaps.
+    ///
+    /// Values are visited from start to end. This is useful for filling dense
+    /// buffers, such as GPU uploads. The results are written straight into the
+    /// spare capacity of the output, which is allocated once.
+    pub fn pack_values_to_vec<U, F>(&self, f: F) -> Vec<U>
+    where
+        U: Copy,
+        F: Fn(&T) -> U,
+    {
+        let len = self.len();
+        let mut output = Vec::with_capacity(len);
+        for (slot, value) in output.spare_capacity_mut().iter_mut().zip(self.values()) {
+            slot.write(f(value));
+        }
+        // SAFETY: the slab holds exactly `len` values, so the first `len` slots
+        // were all written above. `U: Copy` has no drop glue, so if `f` panics
+        // the written slots are simply forgotten.
+        unsafe { output.set_len(len) };
+        output
+    }
+
+    /// Copies the key-value pairs into `dst` from start to end, without gaps.
+    ///
+    /// Returns the number of pairs copied, which is the smaller of
+    /// `self.len()` and `dst.len()`.
+    pub fn copy_to_slice_keyed(&self, dst: &mut [(usize, T)]) -> usize
+    where
+        T: Copy,
+    {
+        let mut count = 0;
+        for (dst, (key, value)) in dst.iter_mut().zip(self.iter()) {
+            *dst = (key.into(), *value);
+            count += 1;
+        }
+        count
+    }
+
+    /// Returns how many values have ever been inserted into the slab.
+    ///
+    /// The counter only ever goes up: it is not reset by removing values or
+    /// clearing the slab. Comparing two readings tells whether anything was
+    /// inserted in between.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if the counter overflows. In release builds it
+    /// wraps around to zero.
+    pub fn total_keys_ever_inserted(&self) -> u64 {
+        self.total_inserts
+    }
+
+    /// Asserts that the internal invariants of the slab hold.
+    ///
+    /// This does nothing in release builds.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if the index is inconsistent with itself, or with
+    /// the entries.
+    pub fn assert_valid(&self) {
+        #[cfg(debug_assertions)]
+        {
+            let words = self.index.words();
+            let count: usize = words.iter().map(|word| word.count_ones() as usize).sum();
+            assert_eq!(
+                self.index.len(),
+                count,
+                "index count does not match its occupied bits"
+            );
+            if let Some(last) = self.index.last_occupied() {
+                assert!(
+                    last < self.index.capacity(),
+                    "key {last} is occupied past the capacity of the index"
+                );
+                assert!(
+                    last < self.entries.len(),
+                    "key {last} is occupied past the end of the entries"
+                );
+            }
+        }
+    }
+
+    /// Prints the occupied keys to stderr as a grid, to help spot
+    /// fragmentation.
+    ///
+    /// Each character covers 4 keys: `█` if any of them is occupied, and `░`
+    /// if all of them are vacant. Each line covers 256 keys. This does nothing
+    /// in release builds.
+    pub fn print_occupied_bitmap(&self) {
+        #[cfg(debug_assertions)]
+        {
+            const KEYS_PER_CHAR: u32 = 4;
+            const WORDS_PER_LINE: usize = 4;
+            for line in self.raw_index_words().chunks(WORDS_PER_LINE) {
+                let mut output = String::new();
+                for word in line {
+                    for group in 0..usize::BITS / KEYS_PER_CHAR {
+                        let bits = (word >> (group * KEYS_PER_CHAR)) & 0b1111;
+                        output.push(if bits != 0 { '█' } else { '░' });
+                    }
+                }
+                eprintln!("{output}");
+            }
+        }
+    }
+
     /// Returns `true` if the map contains a value for the specified key.
     pub fn contains_key(&self, key: Key) -> bool {
         self.index.contains(key.into())
     }
 
+    /// Returns `true` if the map contains a value for every key in `keys`.
+    ///
+    /// Returns `true` if `keys` is empty.
+    pub fn batch_contains_all(&self, keys: &[Key]) -> bool {
+        self.index
+            .contains_all(keys.iter().map(|key| usize::from(*key)))
+    }
+
     /// Returns a reference to the value corresponding to the key.
     pub fn get(&self, key: Key) -> Option<&T> {
         if self.contains_key(key) {
@@ -70,55 +335,894 @@ impl<T> Slab<T> {
         }
     }
 
-    /// Inserts a value into the slab
+    /// Returns a mutable reference to the value at `key`, inserting the result
+    /// of `f` if the key is vacant.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `f` if it fails, without modifying the slab.
+    pub fn get_or_try_insert_with<E, F>(&mut self, key: Key, f: F) -> Result<&mut T, E>
+    where
+        F: FnOnce() -> Result<T, E>,
+    {
+        if !self.contains_key(key) {
+            let value = f()?;
+            self.grow_to_fit(usize::from(key) + 1);
+            self.insert_at(key.into(), value);
+        }
+        // The key was either occupied, or we just inserted into it.
+        Ok(self.get_mut(key).unwrap())
+    }
+
+    /// Returns mutable references to the values at every key in `keys`,
+    /// inserting `default_fn(key)` at each key which is vacant.
+    ///
+    /// The references are returned in the same order as `keys`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a key appears more than once in `keys`. The slab is left
+    /// untouched in that case.
+    pub fn get_or_insert_many<F>(&mut self, keys: &[Key], mut default_fn: F) -> Vec<&mut T>
+    where
+        F: FnMut(Key) -> T,
+    {
+        let mut sorted = keys.to_vec();
+        sorted.sort_unstable();
+        if let Some(pair) = sorted.windows(2).find(|pair| pair[0] == pair[1]) {
+            panic!("key {:?} appears more than once", pair[0]);
+        }
+        for &key in keys {
+            if !self.contains_key(key) {
+                self.grow_to_fit(usize::from(key) + 1);
+                self.insert_at(key.into(), default_fn(key));
+            }
+        }
+        let entries = self.entries.as_mut_ptr();
+        keys.iter()
+            .map(|key| {
+                // SAFETY: every key is occupied, so its entry is in bounds and
+                // initialized. The keys are distinct, so no two references
+                // alias.
+                unsafe { (*entries.add(usize::from(*key))).assume_init_mut() }
+            })
+            .collect()
+    }
+
+    /// Returns mutable references to the values at two keys, inserting
+    /// `T::default()` at either key if it is vacant.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k1` and `k2` are equal.
+    pub fn get_two_or_default_mut(&mut self, k1: Key, k2: Key) -> (&mut T, &mut T)
+    where
+        T: Default,
+    {
+        assert!(k1 != k2, "key {k1:?} is borrowed twice");
+        for key in [k1, k2] {
+            if !self.contains_key(key) {
+                self.grow_to_fit(usize::from(key) + 1);
+                self.insert_at(key.into(), T::default());
+            }
+        }
+        self.pair_mut(k1.into(), k2.into())
+    }
+
+    /// Returns the first vacant key greater than or equal to `hint`.
+    pub fn first_vacant_key_after(&self, hint: Key) -> Key {
+        let index = usize::from(hint);
+        match self.index.unoccupied_from(index).next() {
+            Some(index) => Key::new(index),
+            None => Key::new(index.max(self.capacity())),
+        }
+    }
+
+    /// Inserts a value into the slab
+    ///
+    /// Returns the key for the entry.
+    pub fn insert(&mut self, value: T) -> Key {
+        if self.len() >= self.capacity() {
+            self.grow();
+        }
+        let index = self.index.next_unoccupied();
+        self.insert_at(index, value);
+        Key::new(index)
+    }
+
+    /// Inserts a value into the slab at `hint`, or at the first vacant key
+    /// after it if `hint` is occupied.
+    ///
+    /// Returns the key for the entry.
+    pub fn insert_hint(&mut self, hint: Key, value: T) -> Key {
+        let key = self.first_vacant_key_after(hint);
+        self.grow_to_fit(usize::from(key) + 1);
+        self.insert_at(key.into(), value);
+        key
+    }
+
+    /// Fills every key in `range` with a clone of `value`, and returns the keys
+    /// which were previously vacant.
+    ///
+    /// Occupied keys in the range are overwritten, dropping their old values.
+    /// The slab grows to fit the range if needed. An unbounded range ends at
+    /// the current capacity.
+    pub fn fill_range<R>(&mut self, range: R, value: T) -> Vec<Key>
+    where
+        T: Clone,
+        R: RangeBounds<usize>,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end + 1,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.capacity(),
+        };
+        self.grow_to_fit(end);
+        let mut keys = vec![];
+        for index in start..end {
+            let key = Key::new(index);
+            if self.contains_key(key) {
+                self.replace(key, value.clone());
+            } else {
+                self.insert_at(index, value.clone());
+                keys.push(key);
+            }
+        }
+        keys
+    }
+
+    /// Inserts all values at their given keys, or none of them at all.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error without inserting anything if any of the keys is
+    /// already occupied, or appears more than once in `pairs`.
+    pub fn batch_insert_at<I>(&mut self, pairs: I) -> Result<(), BatchInsertError>
+    where
+        I: IntoIterator<Item = (Key, T)>,
+    {
+        let pairs: Vec<(Key, T)> = pairs.into_iter().collect();
+        let mut keys: Vec<Key> = pairs.iter().map(|(key, _)| *key).collect();
+        if let Some(key) = keys.iter().find(|key| self.contains_key(**key)) {
+            return Err(BatchInsertError::Occupied(*key));
+        }
+        keys.sort_unstable();
+        if let Some(keys) = keys.windows(2).find(|keys| keys[0] == keys[1]) {
+            return Err(BatchInsertError::Duplicate(keys[0]));
+        }
+
+        if let Some(max) = keys.last() {
+            self.grow_to_fit(usize::from(*max) + 1);
+        }
+        for (key, value) in pairs {
+            self.insert_at(key.into(), value);
+        }
+        Ok(())
+    }
+
+    /// Write a value into a vacant slot, growing the entries if needed.
+    fn insert_at(&mut self, index: usize, value: T) {
+        debug_assert!(!self.index.contains(index), "slot {index} is occupied");
+        if index >= self.entries.len() {
+            self.entries.resize_with(index + 1, MaybeUninit::uninit);
+        }
+        self.index.insert(index);
+        self.entries[index] = MaybeUninit::new(value);
+        self.total_inserts += 1;
+    }
+
+    /// Inserts every value into the slab, and returns their keys.
+    ///
+    /// Capacity for exactly `values.len()` more elements is allocated up front,
+    /// so the slab grows at most once.
+    pub fn extend_dense<I>(&mut self, values: I) -> Vec<Key>
+    where
+        I: ExactSizeIterator<Item = T>,
+    {
+        let min_capacity = self.len() + values.len();
+        if self.capacity() < min_capacity {
+            self.resize(min_capacity);
+        }
+        let mut keys = Vec::with_capacity(values.len());
+        keys.extend(values.map(|value| self.insert(value)));
+        keys
+    }
+
+    /// Reserves capacity for at least additional more elements to be inserted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds isize::MAX bytes.
+    pub fn reserve(&mut self, additional: usize) {
+        let new_len = self.index.capacity() + additional;
+        self.resize(new_len);
+    }
+
+    /// Grows the capacity of the `Slab` by its grow factor.
+    ///
+    /// By default this doubles the capacity.
+    pub fn grow(&mut self) {
+        let capacity = self.capacity() as f64 * self.grow_factor;
+        self.resize((capacity.ceil() as usize).max(self.capacity() + 1));
+    }
+
+    /// Grows the capacity of the `Slab` by its grow factor until it can hold
+    /// at least `min_capacity` elements.
+    pub fn grow_to_fit(&mut self, min_capacity: usize) {
+        while self.capacity() < min_capacity {
+            self.grow();
+        }
+    }
+
+    /// Resizes the `Slab` in-place so that `len` is equal to `new_len`.
+    pub fn resize(&mut self, new_len: usize) {
+        self.index.resize(new_len);
+        self.entries.resize_with(new_len, || MaybeUninit::uninit());
+    }
+
+    /// Replace the value at an occupied key, returning the previous value.
+    ///
+    /// Returns `None` without inserting if the key is vacant.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key exceeds the capacity of the `Slab`.
+    pub fn replace(&mut self, key: Key, value: T) -> Option<T> {
+        let index = usize::from(key);
+        assert!(index < self.capacity(), "key {index} is out of bounds");
+        if !self.contains_key(key) {
+            return None;
+        }
+        let output = mem::replace(&mut self.entries[index], MaybeUninit::new(value));
+        // SAFETY: we just confirmed that there was in fact an entry at this index
+        Some(unsafe { output.assume_init() })
+    }
+
+    /// Swaps the values at two occupied keys.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either key is vacant.
+    pub fn swap_values(&mut self, k1: Key, k2: Key) {
+        assert!(self.contains_key(k1), "key {k1:?} is vacant");
+        assert!(self.contains_key(k2), "key {k2:?} is vacant");
+        self.entries.swap(k1.into(), k2.into());
+    }
+
+    /// Swaps the values at each pair of keys, in order.
+    ///
+    /// Later swaps see the result of earlier ones. All keys are checked before
+    /// any values are swapped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any key is vacant. The slab is left untouched in that case.
+    pub fn bulk_swap(&mut self, swaps: &[(Key, Key)]) {
+        for &(k1, k2) in swaps {
+            assert!(self.contains_key(k1), "key {k1:?} is vacant");
+            assert!(self.contains_key(k2), "key {k2:?} is vacant");
+        }
+        for &(k1, k2) in swaps {
+            self.entries.swap(k1.into(), k2.into());
+        }
+    }
+
+    /// Remove and return the value associated with the given key.
+    ///
+    /// The key is then released and may be associated with future stored values.
+    ///
+    /// If the slab was created with [`Slab::with_tombstone_limit`], this may
+    /// compact the slab, moving the remaining entries to new keys.
+    pub fn remove(&mut self, key: Key) -> Option<T> {
+        let output = self.remove_at(key.into())?;
+        self.release_tombstones();
+        Some(output)
+    }
+
+    /// Take the value out of an occupied slot, without ever compacting.
+    pub(crate) fn remove_at(&mut self, index: usize) -> Option<T> {
+        if self.index.remove(index) {
+            let mut output = MaybeUninit::uninit();
+            mem::swap(&mut self.entries[index], &mut output);
+            // SAFETY: we just confirmed that there was in fact an entry at this index
+            Some(unsafe { output.assume_init() })
+        } else {
+            None
+        }
+    }
+
+    /// Compact the slab if we exceed the tombstone limit.
+    fn release_tombstones(&mut self) {
+        let Some(max_tombstones) = self.max_tombstones else {
+            return;
+        };
+        // Vacant slots within the entries, which is what compacting releases.
+        if self.entries.len() - self.len() > max_tombstones {
+            self.compact();
+        }
+    }
+
+    /// Moves all entries to the keys `0..self.len()`, keeping them in key
+    /// order, and releases all vacant slots.
+    ///
+    /// Returns a list of `(old_key, new_key)` pairs, ordered by the old key.
+    pub fn compact(&mut self) -> Vec<(Key, Key)> {
+        let mut next = 0;
+        self.rekey(self.len(), |_| {
+            next += 1;
+            next - 1
+        })
+    }
+
+    /// Shrinks the capacity of the slab to `min_capacity`, unless that would
+    /// remove occupied keys.
+    ///
+    /// Does nothing if the capacity is already less than or equal to
+    /// `min_capacity`. The index is rounded up to a whole number of words, so
+    /// the capacity may stay slightly larger than `min_capacity`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error listing the occupied keys in
+    /// `min_capacity..self.capacity()` if there are any. The slab is left
+    /// untouched in that case.
+    pub fn try_shrink_to(&mut self, min_capacity: usize) -> Result<(), ShrinkError> {
+        if self.capacity() <= min_capacity {
+            return Ok(());
+        }
+        let keys: Vec<Key> = self
+            .keys()
+            .skip_while(|key| usize::from(*key) < min_capacity)
+            .collect();
+        if !keys.is_empty() {
+            return Err(ShrinkError::OccupiedKeysInTruncatedRange { keys });
+        }
+        self.truncate(min_capacity);
+        self.index.resize(min_capacity);
+        self.entries.shrink_to(min_capacity);
+        Ok(())
+    }
+
+    /// Shrinks the capacity of the index as much as possible, without
+    /// reallocating the entries.
+    ///
+    /// Values stay at the same address. The capacity of the slab will be just
+    /// large enough to hold the highest occupied key.
+    pub fn shrink_index_to_fit(&mut self) {
+        self.index.shrink_to_fit();
+    }
+
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// Removes all entries for which `f` returns `false`.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(Key, &mut T) -> bool,
+    {
+        let keys: Vec<Key> = self
+            .iter_mut()
+            .filter_map(|(key, value)| (!f(key, value)).then_some(key))
+            .collect();
+        for key in keys {
+            self.remove_at(key.into());
+        }
+    }
+
+    /// Removes all entries for which `f` returns `true`.
+    ///
+    /// This is the complement of [`Slab::retain`]. Entries are visited from
+    /// start to end. Returns the number of entries removed.
+    pub fn iter_keys_and_remove_where<F>(&mut self, mut f: F) -> usize
+    where
+        F: FnMut(Key, &T) -> bool,
+    {
+        self.drain_where(|key, value| f(key, value)).count()
+    }
+
+    /// Removes consecutive entries with equal values, keeping the first.
+    ///
+    /// Entries are consecutive if no occupied entry lies between them. Returns
+    /// the number of entries removed.
+    pub fn dedup_values(&mut self) -> usize
+    where
+        T: PartialEq,
+    {
+        let mut prev: Option<&T> = None;
+        let mut keys = vec![];
+        for (key, value) in self.iter() {
+            match prev {
+                Some(prev) if prev == value => keys.push(key),
+                _ => prev = Some(value),
+            }
+        }
+        for key in &keys {
+            self.remove_at((*key).into());
+        }
+        keys.len()
+    }
+
+    /// Returns an iterator which removes and yields all key-value pairs for
+    /// which `f` returns `true`.
+    ///
+    /// Entries are visited from start to end. If the iterator is dropped before
+    /// it is exhausted, the remaining matching entries are still removed.
+    pub fn drain_where<F>(&mut self, f: F) -> DrainWhere<'_, T, F>
+    where
+        F: FnMut(Key, &mut T) -> bool,
+    {
+        DrainWhere::new(self, f)
+    }
+
+    /// Split the slab in two based on a predicate.
+    ///
+    /// Entries for which `f` returns `true` remain in `self` under their
+    /// original keys. All other entries are moved into the returned `Slab`,
+    /// which assigns them new keys starting from 0.
+    pub fn partition<F>(&mut self, mut f: F) -> Slab<T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let keys: Vec<Key> = self
+            .iter()
+            .filter_map(|(key, value)| (!f(value)).then_some(key))
+            .collect();
+        let mut output = Slab::with_capacity(keys.len());
+        for key in keys {
+            // `key` was just yielded by `iter`, so it is guaranteed to be occupied.
+            output.insert(self.remove_at(key.into()).unwrap());
+        }
+        output
+    }
+
+    /// Removes all entries with a key greater than or equal to `len`.
+    ///
+    /// Entries with keys in `0..len` are kept under their original keys.
+    pub fn truncate(&mut self, len: usize) {
+        let keys: Vec<Key> = self
+            .keys()
+            .skip_while(|key| usize::from(*key) < len)
+            .collect();
+        for key in keys {
+            self.remove_at(key.into());
+        }
+        if len < self.entries.len() {
+            self.entries.truncate(len);
+            self.index.resize(len);
+        }
+    }
+
+    /// Shifts all keys up by `n`, wrapping around at the capacity of the slab.
+    ///
+    /// Returns a list of `(old_key, new_key)` pairs, ordered by the old key.
+    /// Returns an empty list if the slab has no capacity.
+    pub fn rotate_right(&mut self, n: usize) -> Vec<(Key, Key)> {
+        let capacity = self.capacity();
+        // Without any capacity there are no keys to rotate.
+        if capacity == 0 {
+            return vec![];
+        }
+        let n = n % capacity;
+        self.rekey(capacity, |index| (index + n) % capacity)
+    }
+
+    /// Shifts all keys down by `n`, wrapping around at the capacity of the
+    /// slab.
+    ///
+    /// Returns a list of `(old_key, new_key)` pairs, ordered by the old key.
+    /// Returns an empty list if the slab has no capacity.
+    pub fn rotate_left(&mut self, n: usize) -> Vec<(Key, Key)> {
+        let capacity = self.capacity();
+        // Without any capacity there are no keys to rotate.
+        if capacity == 0 {
+            return vec![];
+        }
+        let n = n % capacity;
+        self.rekey(capacity, |index| (index + capacity - n) % capacity)
+    }
+
+    /// Returns a new slab with every value cloned to the key returned by `f`.
+    ///
+    /// The capacity of the new slab fits the highest new key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `f` maps two keys to the same key.
+    pub fn map_keys<F>(&self, mut f: F) -> Slab<T>
+    where
+        T: Clone,
+        F: FnMut(Key) -> Key,
+    {
+        self.iter()
+            .map(|(key, value)| (f(key), value.clone()))
+            .collect()
+    }
+
+    /// Move every entry to a new key within `capacity`, in key order. `f` must
+    /// map distinct keys to distinct keys.
+    fn rekey(&mut self, capacity: usize, mut f: impl FnMut(usize) -> usize) -> Vec<(Key, Key)> {
+        let mut old = mem::replace(self, Slab::with_capacity(capacity));
+        self.grow_factor = old.grow_factor;
+        self.max_tombstones = old.max_tombstones.take();
+        let total_inserts = old.total_inserts;
+
+        let mut remap = Vec::with_capacity(old.len());
+        for (key, value) in old {
+            let new_key = Key::new(f(key.into()));
+            self.insert_at(new_key.into(), value);
+            remap.push((key, new_key));
+        }
+        // Moving entries around doesn't count as inserting them.
+        self.total_inserts = total_inserts;
+        remap
+    }
+
+    /// Moves the entry at each key `i` to key `perm[i]`, in place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `perm` is not a permutation of `0..self.capacity()`.
+    pub fn apply_permutation(&mut self, perm: &[usize]) {
+        let capacity = self.capacity();
+        assert_eq!(perm.len(), capacity, "permutation must cover the capacity");
+        let mut visited = vec![false; capacity];
+        for &index in perm {
+            assert!(
+                index < capacity && !visited[index],
+                "invalid permutation entry {index}"
+            );
+            visited[index] = true;
+        }
+
+        // Rotate the values through each cycle of the permutation.
+        if self.entries.len() < capacity {
+            self.entries.resize_with(capacity, MaybeUninit::uninit);
+        }
+        visited.fill(false);
+        for start in 0..capacity {
+            let mut current = start;
+            while !visited[current] {
+                visited[current] = true;
+                let next = perm[current];
+                if next == start {
+                    break;
+                }
+                self.entries.swap(start, next);
+                current = next;
+            }
+        }
+
+        let mut index = Indexer::with_capacity(capacity);
+        for occupied in self.index.occupied() {
+            index.insert(perm[occupied]);
+        }
+        self.index = index;
+    }
+
+    /// Returns the number of elements in the map.
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    /// Returns the number of elements in the map as a size hint.
+    ///
+    /// The length is exact, so this is always `(len, Some(len))`.
+    pub fn len_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+
+    /// Returns true if the map contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+
+    /// Returns the number of occupied keys which are less than `key`.
+    pub fn count_occupied_before(&self, key: Key) -> usize {
+        self.index.rank(key.into())
+    }
+
+    /// Returns `true` if the occupied keys form a single block without gaps.
+    ///
+    /// Returns `true` if the slab is empty.
+    pub fn is_contiguous(&self) -> bool {
+        match (self.index.first_occupied(), self.index.last_occupied()) {
+            (Some(first), Some(last)) => last - first + 1 == self.len(),
+            _ => true,
+        }
+    }
+
+    /// Returns the number of runs of consecutive occupied keys.
+    ///
+    /// An empty slab has 0 runs, and a densely packed slab has 1. Every
+    /// occupied key with vacant keys on both sides forms a run of its own.
+    pub fn count_runs(&self) -> usize {
+        let mut runs = 0;
+        // The highest bit of the previous word, which precedes bit 0 of the
+        // current word.
+        let mut carry = 0;
+        for &word in self.index.words() {
+            // A run starts at every occupied key whose predecessor is vacant.
+            let starts = word & !((word << 1) | carry);
+            runs += starts.count_ones() as usize;
+            carry = word >> (usize::BITS - 1);
+        }
+        runs
+    }
+
+    /// Returns the length of the longest run of consecutive vacant keys within
+    /// the capacity of the slab.
+    ///
+    /// Returns 0 if every key is occupied.
+    pub fn max_contiguous_vacant_span(&self) -> usize {
+        let mut longest = 0;
+        let mut run = 0;
+        for &word in self.index.words() {
+            if word == 0 {
+                run += usize::BITS as usize;
+                continue;
+            }
+            let mut word = word;
+            let mut bits = usize::BITS;
+            while bits > 0 {
+                // The vacant keys at the bottom of what's left of the word.
+                let zeros = word.trailing_zeros().min(bits);
+                run += zeros as usize;
+                if zeros == bits {
+                    break;
+                }
+                longest = longest.max(run);
+                run = 0;
+                // Skip over the occupied keys which follow.
+                word >>= zeros;
+                let ones = word.trailing_ones();
+                word = word.checked_shr(ones).unwrap_or(0);
+                bits -= zeros + ones;
+            }
+        }
+        longest.max(run)
+    }
+
+    /// Returns the number of occupied keys in `lo..hi`.
+    ///
+    /// Returns 0 if `hi` is less than or equal to `lo`.
+    pub fn count_occupied_between(&self, lo: Key, hi: Key) -> usize {
+        let (lo, hi) = (usize::from(lo), usize::from(hi));
+        if hi <= lo {
+            return 0;
+        }
+        self.index.rank(hi) - self.index.rank(lo)
+    }
+
+    /// Divides the keys `0..self.capacity()` into `buckets` equally sized
+    /// ranges, and returns the number of occupied keys in each.
+    ///
+    /// If the capacity isn't divisible by `buckets`, the last range is smaller.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buckets` is 0.
+    pub fn occupancy_histogram(&self, buckets: usize) -> Vec<usize> {
+        assert!(buckets != 0, "bucket count must be non-zero");
+        let capacity = self.capacity();
+        let size = capacity.div_ceil(buckets);
+        (0..buckets)
+            .map(|bucket| {
+                let start = (bucket * size).min(capacity);
+                let end = (start + size).min(capacity);
+                self.index.rank(end) - self.index.rank(start)
+            })
+            .collect()
+    }
+
+    /// Returns the key of the `n`th occupied entry, counting from zero.
+    pub fn select_nth_key(&self, n: usize) -> Option<Key> {
+        self.index.occupied().nth(n).map(Key::new)
+    }
+
+    /// Returns the `n` smallest occupied keys.
+    ///
+    /// Returns fewer than `n` keys if the slab holds fewer than `n` entries.
+    pub fn first_n_keys(&self, n: usize) -> Vec<Key> {
+        let mut keys = Vec::with_capacity(n.min(self.len()));
+        keys.extend(self.keys().take(n));
+        keys
+    }
+
+    /// Returns the `n` smallest vacant keys, without reserving them.
+    ///
+    /// These are the keys the next `n` calls to [`Slab::insert`] will return,
+    /// provided nothing is removed in between. Keys past the current capacity
+    /// are included as needed.
+    pub fn first_n_vacant_keys(&self, n: usize) -> Vec<Key> {
+        let mut keys = Vec::with_capacity(n.min(self.capacity() - self.len()));
+        keys.extend(self.index.unoccupied().take(n).map(Key::new));
+        let missing = n - keys.len();
+        let capacity = self.capacity();
+        keys.extend((capacity..capacity.saturating_add(missing)).map(Key::new));
+        keys
+    }
+
+    /// Removes and returns the entries with the `n` smallest occupied keys.
+    ///
+    /// Returns fewer than `n` entries if the slab holds fewer than `n` entries.
+    pub fn take_first_n(&mut self, n: usize) -> Vec<(Key, T)> {
+        let keys = self.first_n_keys(n);
+        let mut output = Vec::with_capacity(keys.len());
+        for key in keys {
+            if let Some(value) = self.remove_at(key.into()) {
+                output.push((key, value));
+            }
+        }
+        output
+    }
+
+    /// Returns the key of an occupied entry chosen uniformly at random.
+    ///
+    /// Returns `None` if the slab is empty.
+    #[cfg(feature = "rand")]
+    pub fn sample_random_key(&self, rng: &mut impl rand::Rng) -> Option<Key> {
+        if self.is_empty() {
+            return None;
+        }
+        self.select_nth_key(rng.gen_range(0..self.len()))
+    }
+
+    /// Returns the keys which are occupied in either `self` or `other`.
+    ///
+    /// The keys are returned from start to end.
+    pub fn union_keys<U>(&self, other: &Slab<U>) -> Vec<Key> {
+        self.combine_keys(other, |a, b| a | b)
+    }
+
+    /// Returns the keys which are occupied in both `self` and `other`.
+    ///
+    /// The keys are returned from start to end.
+    #[doc(alias = "keys_in_common")]
+    pub fn intersection_keys<U>(&self, other: &Slab<U>) -> Vec<Key> {
+        self.combine_keys(other, |a, b| a & b)
+    }
+
+    /// Returns the keys which are occupied in `self` but not in `other`.
+    ///
+    /// The keys are returned from start to end.
+    pub fn difference_keys<U>(&self, other: &Slab<U>) -> Vec<Key> {
+        self.combine_keys(other, |a, b| a & !b)
+    }
+
+    /// Returns the keys which are occupied in either `self` or `other`, but
+    /// not in both.
+    ///
+    /// The keys are returned from start to end.
+    pub fn symmetric_difference_keys<U>(&self, other: &Slab<U>) -> Vec<Key> {
+        self.combine_keys(other, |a, b| a ^ b)
+    }
+
+    /// Returns an iterator over the keys which are occupied in `self` but not
+    /// in `other`.
+    ///
+    /// The keys are returned from start to end. Unlike
+    /// [`Slab::difference_keys`] this does not allocate.
+    pub fn keys_not_in<'a, U>(&'a self, other: &'a Slab<U>) -> KeyDifference<'a> {
+        KeyDifference::new(self, other)
+    }
+
+    /// Removes every entry whose key is occupied in `other`.
+    ///
+    /// Returns the number of entries removed.
+    pub fn difference_update<U>(&mut self, other: &Slab<U>) -> usize {
+        let keys = self.intersection_keys(other);
+        for key in &keys {
+            self.remove_at((*key).into());
+        }
+        keys.len()
+    }
+
+    /// Returns `true` if every key occupied in `self` is also occupied in
+    /// `other`.
+    ///
+    /// Returns `true` if `self` is empty.
+    pub fn is_subset_of_keys<U>(&self, other: &Slab<U>) -> bool {
+        let (lhs, rhs) = (self.index.words(), other.index.words());
+        lhs.iter()
+            .enumerate()
+            .all(|(word_index, lhs)| lhs & !rhs.get(word_index).copied().unwrap_or(0) == 0)
+    }
+
+    /// Combine the index words of both slabs, and return the keys of all set
+    /// bits.
+    fn combine_keys<U>(&self, other: &Slab<U>, op: impl Fn(usize, usize) -> usize) -> Vec<Key> {
+        let (lhs, rhs) = (self.index.words(), other.index.words());
+        let mut keys = vec![];
+        for word_index in 0..lhs.len().max(rhs.len()) {
+            let lhs = lhs.get(word_index).copied().unwrap_or(0);
+            let rhs = rhs.get(word_index).copied().unwrap_or(0);
+            let mut word = op(lhs, rhs);
+            while word != 0 {
+                let bit = word.trailing_zeros() as usize;
+                keys.push(Key::new(word_index * usize::BITS as usize + bit));
+                // Clear the lowest set bit.
+                word &= word - 1;
+            }
+        }
+        keys
+    }
+
+    /// Returns the raw words of the bitmap which tracks the occupied keys.
+    ///
+    /// Bit `key % usize::BITS` of word `key / usize::BITS` is set if `key` is
+    /// occupied. The layout of the index is not stable, and may change between
+    /// releases.
+    pub fn raw_index_words(&self) -> &[usize] {
+        self.index.words()
+    }
+
+    /// Returns the number of occupied keys in the word at `word_index` of the
+    /// index.
     ///
-    /// Returns the key for the entry.
-    pub fn insert(&mut self, value: T) -> Key {
-        let index = self.index.unoccupied().next().unwrap();
-        self.index.insert(index);
-        self.entries.insert(index, MaybeUninit::new(value));
-        Key::new(index)
+    /// Word `i` tracks the keys `i * usize::BITS..(i + 1) * usize::BITS`.
+    /// Returns `None` if the word is out of bounds.
+    pub fn count_ones_in_word(&self, word_index: usize) -> Option<u32> {
+        self.index
+            .words()
+            .get(word_index)
+            .map(|word| word.count_ones())
     }
 
-    /// Reserves capacity for at least additional more elements to be inserted.
+    /// Returns an iterator over pages of `page_size` keys, which each iterate
+    /// over the occupied entries within them.
+    ///
+    /// The pages cover the capacity of the slab from start to end. The last
+    /// page may be shorter than `page_size`.
     ///
     /// # Panics
     ///
-    /// Panics if the new capacity exceeds isize::MAX bytes.
-    pub fn reserve(&mut self, additional: usize) {
-        let new_len = self.index.capacity() + additional;
-        self.resize(new_len);
+    /// Panics if `page_size` is zero.
+    pub fn iter_pages(&self, page_size: usize) -> Pages<'_, T> {
+        Pages::new(self, page_size)
     }
 
-    /// Resizes the `Slab` in-place so that `len` is equal to `new_len`.
-    pub fn resize(&mut self, new_len: usize) {
-        self.index.resize(new_len);
-        self.entries.resize_with(new_len, || MaybeUninit::uninit());
+    /// Returns an iterator over the runs of consecutive occupied keys.
+    ///
+    /// Each run is yielded once as a [`ContiguousBlock`](crate::ContiguousBlock),
+    /// from start to end.
+    pub fn iter_occupied_chunks(&self) -> OccupiedChunks<'_> {
+        OccupiedChunks::new(self)
     }
 
-    /// Remove and return the value associated with the given key.
+    /// Returns an iterator over `(word_index, word)` for every word of the
+    /// index which has at least one occupied key.
     ///
-    /// The key is then released and may be associated with future stored values.
-    pub fn remove(&mut self, key: Key) -> Option<T> {
-        let index = key.into();
-        if self.index.remove(index) {
-            let mut output = MaybeUninit::uninit();
-            mem::swap(&mut self.entries[index], &mut output);
-            // SAFETY: we just confirmed that there was in fact an entry at this index
-            Some(unsafe { output.assume_init() })
-        } else {
-            None
-        }
+    /// Bit `b` of word `i` is set if the key `i * usize::BITS + b` is
+    /// occupied.
+    pub fn iter_occupied_words(&self) -> OccupiedWords<'_> {
+        OccupiedWords::new(self)
     }
 
-    /// Returns the number of elements in the map.
-    pub fn len(&self) -> usize {
-        self.index.len()
+    /// Returns a snapshot of the occupied keys as a bitset.
+    ///
+    /// Bit `key % usize::BITS` of word `key / usize::BITS` is set if `key` is
+    /// occupied.
+    pub fn keys_as_bitset(&self) -> Vec<usize> {
+        self.index.words().to_vec()
     }
 
-    /// Returns true if the map contains no elements.
-    pub fn is_empty(&self) -> bool {
-        self.index.is_empty()
+    /// Returns the lowest occupied key.
+    ///
+    /// Returns `None` if the slab is empty.
+    pub fn min_occupied_key(&self) -> Option<Key> {
+        self.index.first_occupied().map(Key::new)
+    }
+
+    /// Returns the highest occupied key.
+    ///
+    /// Returns `None` if the slab is empty.
+    pub fn max_occupied_key(&self) -> Option<Key> {
+        self.index.last_occupied().map(Key::new)
     }
 
     /// Returns the number of elements the map can hold without reallocating.
@@ -126,6 +1230,23 @@ impl<T> Slab<T> {
         self.index.capacity()
     }
 
+    /// Returns the number of keys the index can track without reallocating.
+    ///
+    /// This is an alias for [`Slab::capacity`], to contrast it with
+    /// [`Slab::entries_capacity`].
+    pub fn index_capacity(&self) -> usize {
+        self.capacity()
+    }
+
+    /// Returns the number of values the backing storage can hold without
+    /// reallocating.
+    ///
+    /// This may differ from [`Slab::capacity`], which is the capacity of the
+    /// index.
+    pub fn entries_capacity(&self) -> usize {
+        self.entries.capacity()
+    }
+
     /// Returns an iterator over all key-value pairs.
     ///
     /// The iterator yields all items from start to end.
@@ -133,6 +1254,59 @@ impl<T> Slab<T> {
         self.into_iter()
     }
 
+    /// Returns an iterator over the keys of all values for which `f` returns
+    /// `true`.
+    ///
+    /// The iterator yields all keys from start to end.
+    pub fn iter_positions<F>(&self, f: F) -> Positions<'_, T, F>
+    where
+        F: Fn(&T) -> bool,
+    {
+        Positions::new(self, f)
+    }
+
+    /// Returns the changes which transform `self` into `other`.
+    ///
+    /// Keys which are vacant in `other` are removed, and keys which are vacant
+    /// in `self` are inserted. Keys whose values differ are both removed and
+    /// inserted.
+    pub fn diff(&self, other: &Slab<T>) -> SlabDelta<T>
+    where
+        T: PartialEq + Clone,
+    {
+        let mut delta = SlabDelta::new();
+        for key in self.union_keys(other) {
+            match (self.get(key), other.get(key)) {
+                (Some(lhs), Some(rhs)) if lhs == rhs => {}
+                (lhs, rhs) => {
+                    if lhs.is_some() {
+                        delta.removes.push(key);
+                    }
+                    if let Some(rhs) = rhs {
+                        delta.inserts.push((key, rhs.clone()));
+                    }
+                }
+            }
+        }
+        delta
+    }
+
+    /// Applies all removes in `delta`, followed by all inserts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an insert targets a key which is still occupied.
+    pub fn apply_delta(&mut self, delta: SlabDelta<T>) {
+        for key in delta.removes {
+            self.remove_at(key.into());
+        }
+        for (key, value) in delta.inserts {
+            assert!(!self.contains_key(key), "key {key:?} is occupied");
+            self.grow_to_fit(usize::from(key) + 1);
+            self.insert_at(key.into(), value);
+        }
+    }
+
     /// Returns an iterator over key-value pairs that allows modifying each
     /// value.
     ///
@@ -141,6 +1315,70 @@ impl<T> Slab<T> {
         self.into_iter()
     }
 
+    /// Returns an iterator over key-value pairs that allows modifying each
+    /// value.
+    ///
+    /// The iterator yields all items from start to end. Unlike
+    /// [`Slab::iter_mut`] it jumps straight to each occupied entry, rather than
+    /// stepping over the vacant entries in between.
+    #[doc(alias = "iter_mut_enumerated_safely")]
+    pub fn iter_mut_safe(&mut self) -> IterMutSafe<'_, T> {
+        IterMutSafe::new(self)
+    }
+
+    /// Returns a cursor over key-value pairs that allows modifying each value,
+    /// and removing the entry last yielded.
+    ///
+    /// The cursor yields all items from start to end.
+    pub fn iter_mut_with_remove(&mut self) -> IterMutWithRemove<'_, T> {
+        IterMutWithRemove::new(self)
+    }
+
+    /// Returns an iterator over key-value pairs that allows modifying each
+    /// value.
+    ///
+    /// The iterator yields all items from start to end. It is backed by
+    /// [`IterMutSafe`], whose cursor hands out each entry at most once, and is
+    /// checked under miri in CI.
+    pub fn iter_pairs_mut(&mut self) -> IterMutSafe<'_, T> {
+        self.iter_mut_safe()
+    }
+
+    /// Returns an iterator over all overlapping windows of `N` consecutive
+    /// key-value pairs.
+    ///
+    /// The windows slide forward by one pair at a time, from start to end. If
+    /// the slab holds fewer than `N` items, the iterator yields nothing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    pub fn iter_enumerated_windows<const N: usize>(&self) -> EnumeratedWindows<'_, T, N> {
+        EnumeratedWindows::new(self)
+    }
+
+    /// Returns an iterator over overlapping windows of `N` consecutive
+    /// key-value pairs.
+    ///
+    /// This is like [`Slab::iter_enumerated_windows`], but yields each window
+    /// as an array of pairs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    pub fn windows_keyed<const N: usize>(&self) -> KeyedWindows<'_, T, N> {
+        KeyedWindows::new(self)
+    }
+
+    /// Returns an iterator over all slots up to and including the highest
+    /// occupied key, yielding `None` for vacant slots.
+    ///
+    /// Unlike [`Slab::iter`] this visits every vacant slot too, which makes it
+    /// proportionally slower for sparse slabs.
+    pub fn enumerate_sparse(&self) -> EnumerateSparse<'_, T> {
+        EnumerateSparse::new(self)
+    }
+
     /// Returns an iterator over all keys.
     ///
     /// The iterator yields all keys from start to end.
@@ -148,6 +1386,18 @@ impl<T> Slab<T> {
         Keys::new(self)
     }
 
+    /// Returns an iterator over all keys in chunks of `chunk_size` keys.
+    ///
+    /// The iterator yields all keys from start to end. The last chunk may
+    /// contain fewer than `chunk_size` keys.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0.
+    pub fn keys_chunk_iter(&self, chunk_size: usize) -> KeysChunks<'_> {
+        KeysChunks::new(self, chunk_size)
+    }
+
     /// Returns an iterator over all values.
     ///
     /// The iterator yields all values from start to end.
@@ -168,6 +1418,370 @@ impl<T> Slab<T> {
     pub fn into_values(self) -> IntoValues<T> {
         IntoValues::new(self)
     }
+
+    /// Consumes `self` and returns an iterator over all key-value pairs, with
+    /// the keys as plain `usize`s.
+    ///
+    /// The iterator yields all items from start to end.
+    pub fn into_key_value_iter(self) -> IntoKeyValues<T> {
+        IntoKeyValues::new(self)
+    }
+
+    /// Consumes `self` and returns all values in ascending key order.
+    #[doc(alias = "drain_to_vec")]
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        let mut output = Vec::with_capacity(self.len());
+        output.extend(self.into_values());
+        output
+    }
+
+    /// Consumes `self` and returns all values, sorted by the key extraction
+    /// function.
+    ///
+    /// The sort is stable: values with equal sort keys stay in ascending key
+    /// order.
+    pub fn into_sorted_by_key<K, F>(self, key_fn: F) -> Vec<T>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        let mut output = self.into_sorted_vec();
+        output.sort_by_key(key_fn);
+        output
+    }
+
+    /// Returns references to all values, sorted with the comparator function.
+    ///
+    /// The slab itself is left untouched. The sort is unstable.
+    pub fn sorted_values_by<F>(&self, mut compare: F) -> Vec<&T>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut output = Vec::with_capacity(self.len());
+        output.extend(self.values());
+        output.sort_unstable_by(|a, b| compare(a, b));
+        output
+    }
+
+    /// Returns all key-value pairs, sorted by their values with the
+    /// comparator function.
+    ///
+    /// The sort is stable: pairs with equal values stay in ascending key order.
+    pub fn to_sorted_by<F>(&self, mut cmp: F) -> Vec<(Key, &T)>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut output = Vec::with_capacity(self.len());
+        output.extend(self.iter());
+        output.sort_by(|(_, a), (_, b)| cmp(a, b));
+        output
+    }
+
+    /// Consumes `self` and returns all key-value pairs as a `BTreeMap`.
+    pub fn into_btreemap(self) -> BTreeMap<Key, T> {
+        self.into_iter().collect()
+    }
+
+    /// Creates a `Slab` from the key-value pairs in a `BTreeMap`.
+    ///
+    /// This is the inverse of [`Slab::into_btreemap`]. The capacity of the slab
+    /// fits the highest key.
+    pub fn from_btreemap(map: BTreeMap<Key, T>) -> Self {
+        map.into_iter().collect()
+    }
+
+    /// Consumes `self` and returns all key-value pairs as a `HashMap`, keyed by
+    /// [`Key`].
+    ///
+    /// This is [`Slab::into_map`] with the key type fixed to `Key`.
+    pub fn into_hashmap(self) -> HashMap<Key, T> {
+        self.into_map()
+    }
+
+    /// Consumes `self` and returns all key-value pairs as a `HashMap`.
+    ///
+    /// Keys are converted to `K` using its `From<Key>` implementation.
+    pub fn into_map<K>(self) -> HashMap<K, T>
+    where
+        K: From<Key> + Eq + Hash,
+    {
+        let mut output = HashMap::with_capacity(self.len());
+        output.extend(self.into_iter().map(|(key, value)| (K::from(key), value)));
+        output
+    }
+
+    /// Returns the first key for which `f` returns `true`.
+    ///
+    /// Entries are visited from start to end.
+    pub fn find<F>(&self, f: F) -> Option<Key>
+    where
+        F: FnMut(Key, &T) -> bool,
+    {
+        self.find_from(Key::new(0), f)
+    }
+
+    /// Returns the first key greater than or equal to `start` for which `f`
+    /// returns `true`.
+    ///
+    /// Entries are visited from `start` to end. Passing the key after a
+    /// previous match resumes the search.
+    pub fn find_from<F>(&self, start: Key, mut f: F) -> Option<Key>
+    where
+        F: FnMut(Key, &T) -> bool,
+    {
+        let mut cursor = usize::from(start);
+        while let Some(index) = self.index.next_occupied(cursor) {
+            let key = Key::new(index);
+            if f(key, self.get(key)?) {
+                return Some(key);
+            }
+            cursor = index + 1;
+        }
+        None
+    }
+
+    /// Returns the smallest key whose value `f` returns `true` for.
+    ///
+    /// Entries are visited from start to end, stopping at the first match.
+    pub fn select_min_key_where<F>(&self, mut f: F) -> Option<Key>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.find(|_, value| f(value))
+    }
+
+    /// Returns the largest key whose value `f` returns `true` for.
+    ///
+    /// Entries are visited from end to start, stopping at the first match.
+    pub fn select_max_key_where<F>(&self, mut f: F) -> Option<Key>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let words = self.index.words();
+        for (word_index, &word) in words.iter().enumerate().rev() {
+            let mut word = word;
+            while word != 0 {
+                let bit = usize::BITS - 1 - word.leading_zeros();
+                let key = Key::new(word_index * usize::BITS as usize + bit as usize);
+                if f(self.get(key)?) {
+                    return Some(key);
+                }
+                // Clear the highest set bit.
+                word &= !(1 << bit);
+            }
+        }
+        None
+    }
+
+    /// Applies `f` to each key-value pair and returns the first non-`None`
+    /// result.
+    ///
+    /// Entries are visited from start to end.
+    pub fn find_map<U, F>(&self, mut f: F) -> Option<U>
+    where
+        F: FnMut(Key, &T) -> Option<U>,
+    {
+        self.iter().find_map(|(key, value)| f(key, value))
+    }
+
+    /// Applies `f` to each key and mutable value and returns the first
+    /// non-`None` result.
+    ///
+    /// Entries are visited from start to end.
+    pub fn find_map_mut<U, F>(&mut self, mut f: F) -> Option<U>
+    where
+        F: FnMut(Key, &mut T) -> Option<U>,
+    {
+        self.iter_mut().find_map(|(key, value)| f(key, value))
+    }
+
+    /// Returns `true` if `f` returns `true` for every key-value pair.
+    ///
+    /// Returns `true` if the slab is empty. Stops at the first pair for which
+    /// `f` returns `false`.
+    pub fn all<F>(&self, mut f: F) -> bool
+    where
+        F: FnMut(Key, &T) -> bool,
+    {
+        self.iter().all(|(key, value)| f(key, value))
+    }
+
+    /// Returns `true` if `f` returns `true` for any key-value pair.
+    ///
+    /// Returns `false` if the slab is empty. Stops at the first pair for which
+    /// `f` returns `true`.
+    pub fn any<F>(&self, mut f: F) -> bool
+    where
+        F: FnMut(Key, &T) -> bool,
+    {
+        self.iter().any(|(key, value)| f(key, value))
+    }
+
+    /// Calls `f` on each key-value pair.
+    ///
+    /// Entries are visited from start to end.
+    pub fn for_each<F>(&self, mut f: F)
+    where
+        F: FnMut(Key, &T),
+    {
+        self.iter().for_each(|(key, value)| f(key, value))
+    }
+
+    /// Calls `f` on each key and mutable value.
+    ///
+    /// Entries are visited from start to end.
+    pub fn for_each_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(Key, &mut T),
+    {
+        self.iter_mut().for_each(|(key, value)| f(key, value))
+    }
+
+    /// Calls `f` on each key-value pair along with a mutable context, and
+    /// returns the context.
+    ///
+    /// Entries are visited from start to end.
+    pub fn iter_with_context<C, F>(&self, mut ctx: C, mut f: F) -> C
+    where
+        F: FnMut(&mut C, Key, &T),
+    {
+        for (key, value) in self.iter() {
+            f(&mut ctx, key, value);
+        }
+        ctx
+    }
+
+    /// Calls `f` on every unordered pair of distinct entries, with mutable
+    /// references to both values.
+    ///
+    /// Each pair is visited once, as `(k1, k2, v1, v2)` with `k1 < k2`. Pairs
+    /// are visited in ascending order of `k1`, then `k2`.
+    pub fn for_each_pair_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(Key, Key, &mut T, &mut T),
+    {
+        let indexes: Vec<usize> = self.index.occupied().collect();
+        for (n, &i) in indexes.iter().enumerate() {
+            for &j in &indexes[n + 1..] {
+                let (lhs, rhs) = self.pair_mut(i, j);
+                f(Key::new(i), Key::new(j), lhs, rhs);
+            }
+        }
+    }
+
+    /// Returns mutable references to the values at two distinct occupied
+    /// indexes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the indexes are equal, or if either is vacant.
+    fn pair_mut(&mut self, i: usize, j: usize) -> (&mut T, &mut T) {
+        assert!(i != j, "index {i} is borrowed twice");
+        assert!(self.index.contains(i), "index {i} is vacant");
+        assert!(self.index.contains(j), "index {j} is vacant");
+        let (head, tail) = self.entries.split_at_mut(i.max(j));
+        let (lo, hi) = (&mut head[i.min(j)], &mut tail[0]);
+        // SAFETY: we just confirmed that both indexes are occupied, so both
+        // values are initialized.
+        let (lo, hi) = unsafe { (lo.assume_init_mut(), hi.assume_init_mut()) };
+        match i < j {
+            true => (lo, hi),
+            false => (hi, lo),
+        }
+    }
+
+    /// Calls `f` on each value, allowing it to be modified in place.
+    ///
+    /// Entries are visited from start to end.
+    pub fn map_in_place<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        self.values_mut().for_each(f)
+    }
+
+    /// Calls `f` on each key-value pair in parallel.
+    ///
+    /// Entries are visited in no particular order. The work is split over the
+    /// words of the index, so vacant slots are skipped a word at a time.
+    #[cfg(feature = "rayon")]
+    pub fn par_for_each<F>(&self, f: F)
+    where
+        T: Sync,
+        F: Fn(Key, &T) + Sync,
+    {
+        use rayon::prelude::*;
+        let bits = usize::BITS as usize;
+        self.index
+            .words()
+            .par_iter()
+            .enumerate()
+            .for_each(|(word_index, word)| {
+                let mut word = *word;
+                while word != 0 {
+                    let index = word_index * bits + word.trailing_zeros() as usize;
+                    // SAFETY: the bit for this index is set, so the entry is occupied
+                    let value = unsafe { self.entries[index].assume_init_ref() };
+                    f(Key::new(index), value);
+                    // Clear the lowest set bit.
+                    word &= word - 1;
+                }
+            })
+    }
+
+    /// Returns an iterator over runs of key-value pairs which `group_fn` maps
+    /// to the same group.
+    ///
+    /// Pairs are visited from start to end, and each run of consecutive pairs
+    /// in the same group is yielded as `(group, pairs)`. No map of groups is
+    /// kept, so a group which isn't contiguous is yielded once per run.
+    pub fn iter_key_groups<F>(&self, group_fn: F) -> KeyGroups<'_, T, F>
+    where
+        F: Fn(Key) -> usize,
+    {
+        KeyGroups::new(self, group_fn)
+    }
+
+    /// Groups all key-value pairs by the top `BITS` bits of their key.
+    ///
+    /// Returns `(prefix, pairs)` for each non-empty group, ordered by prefix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `BITS` exceeds `usize::BITS`.
+    pub fn group_by_key_prefix<const BITS: usize>(&self) -> Vec<(usize, Vec<(Key, &T)>)> {
+        assert!(BITS <= usize::BITS as usize, "prefix exceeds key size");
+        let shift = usize::BITS - BITS as u32;
+        let mut groups: Vec<(usize, Vec<(Key, &T)>)> = vec![];
+        // Keys are yielded in order, so entries sharing a prefix are adjacent.
+        for (key, value) in self.iter() {
+            let prefix = usize::from(key).checked_shr(shift).unwrap_or(0);
+            match groups.last_mut() {
+                Some((last, group)) if *last == prefix => group.push((key, value)),
+                _ => groups.push((prefix, vec![(key, value)])),
+            }
+        }
+        groups
+    }
+
+    /// Returns the number of values for which `f` returns `true`.
+    pub fn count_where<F>(&self, mut f: F) -> usize
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.values().filter(|value| f(value)).count()
+    }
+
+    /// Folds every key-value pair into an accumulator, starting from `init`.
+    ///
+    /// Pairs are visited in ascending key order.
+    pub fn fold<B, F>(&self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Key, &T) -> B,
+    {
+        self.iter()
+            .fold(init, |acc, (key, value)| f(acc, key, value))
+    }
 }
 
 impl<T> IntoIterator for Slab<T> {
@@ -212,6 +1826,31 @@ impl<T> FromIterator<T> for Slab<T> {
     }
 }
 
+/// Reconstructs a `Slab` with the exact key layout of the pairs.
+///
+/// # Panics
+///
+/// Panics if the iterator yields the same key more than once.
+impl<T> FromIterator<(Key, T)> for Slab<T> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (Key, T)>,
+    {
+        let pairs: Vec<(Key, T)> = iter.into_iter().collect();
+        let capacity = match pairs.iter().map(|(key, _)| usize::from(*key)).max() {
+            Some(max) => max + 1,
+            None => 0,
+        };
+        let mut slab = Slab::with_capacity(capacity);
+        slab.resize(capacity);
+        for (key, value) in pairs {
+            assert!(!slab.contains_key(key), "duplicate key {key:?}");
+            slab.insert_at(key.into(), value);
+        }
+        slab
+    }
+}
+
 impl<T> Extend<T> for Slab<T> {
     fn extend<I>(&mut self, iter: I)
     where
@@ -226,6 +1865,23 @@ impl<T> Extend<T> for Slab<T> {
     }
 }
 
+/// Inserts a value into the slab, discarding its key.
+impl<T> AddAssign<T> for Slab<T> {
+    fn add_assign(&mut self, value: T) {
+        self.insert(value);
+    }
+}
+
+/// Inserts a value into the slab, discarding its key.
+impl<T> Add<T> for Slab<T> {
+    type Output = Slab<T>;
+
+    fn add(mut self, value: T) -> Self::Output {
+        self.insert(value);
+        self
+    }
+}
+
 /// Returns a reference to the value corresponding to the supplied key.
 ///
 /// # Panics
@@ -235,7 +1891,10 @@ impl<T> Index<Key> for Slab<T> {
     type Output = T;
 
     fn index(&self, index: Key) -> &Self::Output {
-        self.get(index).unwrap()
+        match self.get(index) {
+            Some(value) => value,
+            None => key_not_present(index, self.capacity(), self.len()),
+        }
     }
 }
 
@@ -246,10 +1905,23 @@ impl<T> Index<Key> for Slab<T> {
 /// Panics if the key is not present in the `Slab`.
 impl<T> IndexMut<Key> for Slab<T> {
     fn index_mut(&mut self, index: Key) -> &mut Self::Output {
-        self.get_mut(index).unwrap()
+        let (capacity, len) = (self.capacity(), self.len());
+        match self.get_mut(index) {
+            Some(value) => value,
+            None => key_not_present(index, capacity, len),
+        }
     }
 }
 
+#[cold]
+#[track_caller]
+fn key_not_present(key: Key, capacity: usize, len: usize) -> ! {
+    panic!(
+        "beton: slab index out of bounds: key `{}` not present (slab capacity: {capacity}, len: {len})",
+        key.index()
+    )
+}
+
 impl<T> Drop for Slab<T> {
     fn drop(&mut self) {
         for index in self.index.occupied() {
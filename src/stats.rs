@@ -0,0 +1,169 @@
+use crate::{Key, Slab};
+
+use std::ops::Deref;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// A snapshot of the allocation statistics of a [`StatsSlab`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SlabStats {
+    /// How many values have been inserted?
+    pub inserts: u64,
+    /// How many values have been removed?
+    pub removes: u64,
+    /// How many times has the slab reallocated?
+    pub grows: u64,
+}
+
+/// A slab allocator which tracks allocation statistics.
+///
+/// Dereferences to the underlying [`Slab`] for read-only access.
+#[derive(Debug)]
+pub struct StatsSlab<T> {
+    slab: Slab<T>,
+    inserts: AtomicU64,
+    removes: AtomicU64,
+    grows: AtomicU64,
+}
+
+impl<T> Default for StatsSlab<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> StatsSlab<T> {
+    /// Creates an empty `StatsSlab`.
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Creates an empty `StatsSlab` with at least the specified capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            slab: Slab::with_capacity(capacity),
+            inserts: AtomicU64::new(0),
+            removes: AtomicU64::new(0),
+            grows: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns a snapshot of the statistics collected so far.
+    pub fn stats(&self) -> SlabStats {
+        SlabStats {
+            inserts: self.inserts.load(Ordering::Relaxed),
+            removes: self.removes.load(Ordering::Relaxed),
+            grows: self.grows.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Inserts a value into the slab
+    ///
+    /// Returns the key for the entry.
+    pub fn insert(&mut self, value: T) -> Key {
+        let capacity = self.slab.capacity();
+        let key = self.slab.insert(value);
+        self.inserts.fetch_add(1, Ordering::Relaxed);
+        self.track_grow(capacity);
+        key
+    }
+
+    /// Remove and return the value associated with the given key.
+    ///
+    /// The key is then released and may be associated with future stored values.
+    pub fn remove(&mut self, key: Key) -> Option<T> {
+        let output = self.slab.remove(key);
+        if output.is_some() {
+            self.removes.fetch_add(1, Ordering::Relaxed);
+        }
+        output
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key.
+    pub fn get_mut(&mut self, key: Key) -> Option<&mut T> {
+        self.slab.get_mut(key)
+    }
+
+    /// Clears the map, removing all key-value pairs. Keeps the allocated memory for reuse.
+    pub fn clear(&mut self) {
+        self.slab.clear();
+    }
+
+    /// Reserves capacity for at least additional more elements to be inserted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds isize::MAX bytes.
+    pub fn reserve(&mut self, additional: usize) {
+        let capacity = self.slab.capacity();
+        self.slab.reserve(additional);
+        self.track_grow(capacity);
+    }
+
+    /// Resizes the `Slab` in-place so that `len` is equal to `new_len`.
+    pub fn resize(&mut self, new_len: usize) {
+        let capacity = self.slab.capacity();
+        self.slab.resize(new_len);
+        self.track_grow(capacity);
+    }
+
+    /// Consumes `self` and returns the underlying `Slab`.
+    pub fn into_inner(self) -> Slab<T> {
+        self.slab
+    }
+
+    /// Record a reallocation if the capacity grew past `prev_capacity`.
+    #[inline]
+    fn track_grow(&self, prev_capacity: usize) {
+        if self.slab.capacity() > prev_capacity {
+            self.grows.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+impl<T> Deref for StatsSlab<T> {
+    type Target = Slab<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.slab
+    }
+}
+
+impl<T> From<Slab<T>> for StatsSlab<T> {
+    fn from(slab: Slab<T>) -> Self {
+        Self {
+            slab,
+            inserts: AtomicU64::new(0),
+            removes: AtomicU64::new(0),
+            grows: AtomicU64::new(0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let mut slab = StatsSlab::new();
+        let key = slab.insert(1);
+        slab.insert(2);
+        assert_eq!(slab.remove(key), Some(1));
+        assert_eq!(slab.remove(key), None);
+        slab.reserve(1024);
+
+        let stats = slab.stats();
+        assert_eq!(stats.inserts, 2);
+        assert_eq!(stats.removes, 1);
+        assert_eq!(stats.grows, 1);
+        assert_eq!(slab.len(), 1);
+    }
+
+    #[test]
+    fn default() {
+        struct NonDefault;
+        let slab: StatsSlab<NonDefault> = StatsSlab::default();
+        assert!(slab.is_empty());
+        assert_eq!(slab.stats(), SlabStats::default());
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{Key, Slab};
+
+use std::time::{Duration, Instant};
+
+/// A slab allocator which records when each entry was inserted.
+#[derive(Debug)]
+pub struct TimestampedSlab<T> {
+    slab: Slab<(Instant, T)>,
+}
+
+impl<T> Default for TimestampedSlab<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> TimestampedSlab<T> {
+    /// Creates an empty `TimestampedSlab`.
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Creates an empty `TimestampedSlab` with at least the specified capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            slab: Slab::with_capacity(capacity),
+        }
+    }
+
+    /// Inserts a value into the slab, recording the current time.
+    ///
+    /// Returns the key for the entry.
+    pub fn insert(&mut self, value: T) -> Key {
+        self.slab.insert((Instant::now(), value))
+    }
+
+    /// Remove and return the value associated with the given key.
+    pub fn remove(&mut self, key: Key) -> Option<T> {
+        self.slab.remove(key).map(|(_, value)| value)
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    pub fn get(&self, key: Key) -> Option<&T> {
+        self.slab.get(key).map(|(_, value)| value)
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key.
+    pub fn get_mut(&mut self, key: Key) -> Option<&mut T> {
+        self.slab.get_mut(key).map(|(_, value)| value)
+    }
+
+    /// Returns the time at which the value for the key was inserted.
+    pub fn inserted_at(&self, key: Key) -> Option<Instant> {
+        self.slab.get(key).map(|(instant, _)| *instant)
+    }
+
+    /// Returns `true` if the map contains a value for the specified key.
+    pub fn contains_key(&self, key: Key) -> bool {
+        self.slab.contains_key(key)
+    }
+
+    /// Returns the number of elements in the map.
+    pub fn len(&self) -> usize {
+        self.slab.len()
+    }
+
+    /// Returns true if the map contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.slab.is_empty()
+    }
+
+    /// Remove all entries which were inserted more than `max_age` ago.
+    ///
+    /// Returns the number of entries removed.
+    pub fn evict_older_than(&mut self, max_age: Duration) -> usize {
+        let now = Instant::now();
+        let len = self.slab.len();
+        self.slab
+            .retain(|_, (instant, _)| now.duration_since(*instant) <= max_age);
+        len - self.slab.len()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn evict_older_than() {
+        let mut slab = TimestampedSlab::new();
+        let one = slab.insert(1);
+        let two = slab.insert(2);
+
+        assert_eq!(slab.evict_older_than(Duration::from_secs(60 * 60)), 0);
+        assert_eq!(slab.get(one), Some(&1));
+        assert_eq!(slab.get(two), Some(&2));
+
+        // Sleeping guarantees both entries are strictly older than zero.
+        std::thread::sleep(Duration::from_millis(1));
+        assert_eq!(slab.evict_older_than(Duration::ZERO), 2);
+        assert!(slab.is_empty());
+    }
+}
@@ -15,3 +15,61 @@ fn remove_out_of_bounds() {
     let mut subject: Slab<usize> = Slab::new();
     subject.remove(4215.into());
 }
+
+/// Test that reusing a vacated slot doesn't shift the entries after it.
+#[test]
+fn insert_into_vacant_slot() {
+    let mut subject: Slab<usize> = Slab::new();
+    subject.insert(0);
+    let key = subject.insert(1);
+    subject.insert(2);
+    subject.remove(key);
+    assert_eq!(subject.insert(9), key);
+    assert_eq!(subject.get(0.into()), Some(&0));
+    assert_eq!(subject.get(1.into()), Some(&9));
+    assert_eq!(subject.get(2.into()), Some(&2));
+}
+
+/// Test we grow the index rather than panic once every slot is occupied.
+#[test]
+fn insert_past_capacity() {
+    let mut subject: Slab<usize> = Slab::with_capacity(128);
+    let capacity = subject.capacity();
+    for n in 0..=capacity {
+        assert_eq!(subject.insert(n), n.into());
+    }
+    assert_eq!(subject.len(), capacity + 1);
+    assert_eq!(subject.get(capacity.into()), Some(&capacity));
+}
+
+/// Test removing a key twice only yields its value once.
+#[test]
+fn remove_twice() {
+    let mut subject: Slab<usize> = Slab::new();
+    subject.insert(0);
+    let key = subject.insert(1);
+    assert_eq!(subject.remove(key), Some(1));
+    assert_eq!(subject.remove(key), None);
+    assert_eq!(subject.len(), 1);
+}
+
+/// Test clearing the slab drops the values it contains.
+#[test]
+fn clear_drops_values() {
+    let value = std::rc::Rc::new(());
+    let mut subject = Slab::new();
+    subject.insert(value.clone());
+    subject.clear();
+    assert_eq!(std::rc::Rc::strong_count(&value), 1);
+}
+
+/// Test rotating a slab without any capacity doesn't divide by zero.
+#[test]
+fn rotate_zero_capacity() {
+    let mut subject: Slab<usize> = (0..200).collect();
+    subject.truncate(0);
+    assert_eq!(subject.capacity(), 0);
+    assert_eq!(subject.rotate_right(3), vec![]);
+    assert_eq!(subject.rotate_left(3), vec![]);
+    assert!(subject.is_empty());
+}
@@ -0,0 +1,1259 @@
+use beton::{ShrinkError, Slab};
+
+#[test]
+fn partition() {
+    let mut slab: Slab<usize> = (0..6).collect();
+    let odd = slab.partition(|n| n % 2 == 0);
+
+    assert_eq!(slab.len(), 3);
+    assert_eq!(slab.get(0.into()), Some(&0));
+    assert_eq!(slab.get(2.into()), Some(&2));
+    assert_eq!(slab.get(4.into()), Some(&4));
+    assert!(!slab.contains_key(1.into()));
+
+    let odd: Vec<_> = odd.into_iter().collect();
+    assert_eq!(odd, vec![(0.into(), 1), (1.into(), 3), (2.into(), 5)]);
+}
+
+#[test]
+fn from_iter_pairs() {
+    let pairs = vec![(3.into(), 'c'), (0.into(), 'a'), (70.into(), 'd')];
+    let slab: Slab<char> = pairs.into_iter().collect();
+
+    assert_eq!(slab.len(), 3);
+    assert_eq!(slab.get(0.into()), Some(&'a'));
+    assert_eq!(slab.get(3.into()), Some(&'c'));
+    assert_eq!(slab.get(70.into()), Some(&'d'));
+    assert!(!slab.contains_key(1.into()));
+}
+
+#[test]
+#[should_panic]
+fn from_iter_pairs_duplicate() {
+    let _: Slab<char> = [(1.into(), 'a'), (1.into(), 'b')].into_iter().collect();
+}
+
+#[test]
+fn retain() {
+    let mut slab: Slab<usize> = (0..6).collect();
+    slab.retain(|key, value| {
+        *value *= 10;
+        usize::from(key) < 3
+    });
+    let pairs: Vec<_> = slab.into_iter().collect();
+    assert_eq!(pairs, vec![(0.into(), 0), (1.into(), 10), (2.into(), 20)]);
+}
+
+#[test]
+fn find_map() {
+    let mut slab: Slab<usize> = (0..6).collect();
+    let found = slab.find_map(|key, value| (*value > 2).then(|| (key, value * 2)));
+    assert_eq!(found, Some((3.into(), 6)));
+    assert_eq!(slab.find_map(|_, value| (*value > 9).then_some(())), None);
+
+    let found = slab.find_map_mut(|key, value| {
+        *value += 1;
+        (*value == 2).then_some(key)
+    });
+    assert_eq!(found, Some(1.into()));
+    assert_eq!(slab[0.into()], 1);
+    assert_eq!(slab[2.into()], 2);
+}
+
+#[test]
+fn all_any() {
+    let slab: Slab<usize> = (0..6).collect();
+    assert!(slab.all(|key, value| usize::from(key) == *value));
+    assert!(!slab.all(|_, value| *value < 5));
+    assert!(slab.any(|_, value| *value == 5));
+    assert!(!slab.any(|_, value| *value > 5));
+
+    let empty: Slab<usize> = Slab::new();
+    assert!(empty.all(|_, _| false));
+    assert!(!empty.any(|_, _| true));
+}
+
+#[test]
+fn count_where() {
+    let mut slab: Slab<usize> = (0..6).collect();
+    slab.remove(4.into());
+    assert_eq!(slab.count_where(|value| value % 2 == 0), 2);
+    assert_eq!(slab.count_where(|_| true), slab.len());
+}
+
+#[test]
+fn truncate() {
+    let mut slab: Slab<usize> = (0..200).collect();
+    slab.remove(1.into());
+    slab.truncate(3);
+    assert_eq!(slab.len(), 2);
+    assert_eq!(slab.get(0.into()), Some(&0));
+    assert_eq!(slab.get(2.into()), Some(&2));
+    assert!(!slab.contains_key(3.into()));
+    assert!(!slab.contains_key(199.into()));
+
+    assert_eq!(slab.insert(7), 1.into());
+    assert_eq!(slab.insert(8), 3.into());
+}
+
+#[test]
+fn with_grow_factor() {
+    let mut slab = Slab::with_grow_factor(256, 1.5);
+    assert_eq!(slab.capacity(), 256);
+    for n in 0..=256 {
+        slab.insert(n);
+    }
+    assert_eq!(slab.capacity(), 384);
+    assert_eq!(slab.get(256.into()), Some(&256));
+}
+
+#[test]
+fn with_tombstone_limit() {
+    let mut slab = Slab::with_tombstone_limit(4);
+    let keys: Vec<_> = (0..16).map(|n| slab.insert(n)).collect();
+    // Removing from the back never moves the entries in front.
+    for key in keys[8..].iter().rev() {
+        assert!(slab.remove(*key).is_some());
+    }
+    assert_eq!(slab.len(), 8);
+    assert!(slab.all(|key, value| usize::from(key) == *value));
+    assert_eq!(slab.insert(100), 8.into());
+}
+
+#[test]
+fn with_tombstone_limit_interior() {
+    let mut slab = Slab::with_tombstone_limit(4);
+    for n in 0..300 {
+        slab.insert(n);
+    }
+    // Removing from the front leaves vacant slots before the last entry.
+    for _ in 0..299 {
+        let key = slab.min_occupied_key().unwrap();
+        slab.remove(key);
+    }
+    assert_eq!(slab.len(), 1);
+    assert!(slab.capacity() < 300);
+    assert!(slab.entries_capacity() <= 5);
+    let (key, value) = slab.iter().next().unwrap();
+    assert_eq!(*value, 299);
+    assert!(usize::from(key) <= 4);
+}
+
+#[test]
+fn compact() {
+    let mut slab: Slab<char> = "abcde".chars().collect();
+    slab.remove(1.into());
+    slab.remove(3.into());
+    let remap = slab.compact();
+    assert_eq!(
+        remap,
+        vec![
+            (0.into(), 0.into()),
+            (2.into(), 1.into()),
+            (4.into(), 2.into())
+        ]
+    );
+    let pairs: Vec<_> = slab
+        .iter()
+        .map(|(key, value)| (key.index(), *value))
+        .collect();
+    assert_eq!(pairs, vec![(0, 'a'), (1, 'c'), (2, 'e')]);
+    assert_eq!(slab.total_keys_ever_inserted(), 5);
+    assert_eq!(slab.insert('f'), 3.into());
+}
+
+#[test]
+fn debug() {
+    let mut slab = Slab::new();
+    slab.insert("hello");
+    let key = slab.insert("beton");
+    slab.insert("world");
+    slab.remove(key);
+    assert_eq!(format!("{slab:?}"), r#"Slab {0: "hello", 2: "world"}"#);
+}
+
+#[test]
+fn replace() {
+    let mut slab = Slab::new();
+    let key = slab.insert(1);
+    assert_eq!(slab.replace(key, 2), Some(1));
+    assert_eq!(slab.get(key), Some(&2));
+    assert_eq!(slab.replace(1.into(), 3), None);
+    assert!(!slab.contains_key(1.into()));
+}
+
+#[test]
+#[should_panic]
+fn replace_out_of_bounds() {
+    let mut slab = Slab::new();
+    let capacity = slab.capacity();
+    slab.replace(capacity.into(), 1);
+}
+
+#[test]
+fn grow() {
+    let mut slab: Slab<usize> = Slab::with_capacity(256);
+    slab.grow();
+    assert_eq!(slab.capacity(), 512);
+
+    let mut slab: Slab<usize> = Slab::with_grow_factor(256, 1.5);
+    slab.grow_to_fit(500);
+    assert_eq!(slab.capacity(), 576);
+    slab.grow_to_fit(10);
+    assert_eq!(slab.capacity(), 576);
+}
+
+#[test]
+fn iter_pairs_mut() {
+    let mut slab: Slab<usize> = (0..200).collect();
+    slab.retain(|key, _| usize::from(key) % 3 == 0);
+    for (key, value) in slab.iter_pairs_mut() {
+        *value += usize::from(key);
+    }
+    assert!(slab.all(|key, value| *value == usize::from(key) * 2));
+    assert_eq!(slab.len(), 67);
+}
+
+#[test]
+fn iter_pairs_mut_across_gaps() {
+    let mut slab: Slab<usize> = (0..200).collect();
+    slab.retain(|key, _| [0, 1, 63, 64, 65, 130, 199].contains(&usize::from(key)));
+
+    // Hold on to every reference at once, so miri catches any aliasing.
+    let pairs: Vec<_> = slab.iter_pairs_mut().collect();
+    assert_eq!(pairs.len(), 7);
+    for (key, value) in pairs {
+        *value += usize::from(key);
+    }
+    let values: Vec<&mut usize> = slab.values_mut().collect();
+    assert_eq!(values.len(), 7);
+    for value in values {
+        *value /= 2;
+    }
+    assert!(slab.all(|key, value| usize::from(key) == *value));
+}
+
+#[test]
+fn copy_from() {
+    let mut src: Slab<u32> = (0..300).collect();
+    src.remove(1.into());
+
+    let mut slab: Slab<u32> = (0..4).map(|n| n * 10).collect();
+    slab.copy_from(&src);
+    assert_eq!(slab.len(), 299);
+    assert!(!slab.contains_key(1.into()));
+    assert!(slab.all(|key, value| usize::from(key) == *value as usize));
+
+    let mut slab: Slab<u32> = Slab::with_capacity(1024);
+    slab.copy_from(&[7, 8].into_iter().collect());
+    assert_eq!(slab.capacity(), 1024);
+    assert_eq!(slab.get(1.into()), Some(&8));
+    assert_eq!(slab.insert(9), 2.into());
+}
+
+#[test]
+fn clear_and_release() {
+    let value = std::rc::Rc::new(());
+    let mut slab: Slab<_> = (0..300).map(|_| value.clone()).collect();
+    slab.clear_and_release();
+    assert!(slab.is_empty());
+    assert_eq!(slab.capacity(), Slab::<()>::new().capacity());
+    assert_eq!(std::rc::Rc::strong_count(&value), 1);
+}
+
+#[test]
+fn add_assign() {
+    let mut slab = Slab::new();
+    slab += "root";
+    slab += "child";
+    let slab = slab + "grandchild";
+    assert_eq!(slab.len(), 3);
+    assert_eq!(slab[2.into()], "grandchild");
+}
+
+#[test]
+fn for_each() {
+    let mut slab: Slab<usize> = (0..6).collect();
+    slab.for_each_mut(|key, value| *value += usize::from(key));
+    let mut sum = 0;
+    slab.for_each(|_, value| sum += value);
+    assert_eq!(sum, 30);
+}
+
+#[test]
+fn count_occupied_before() {
+    let mut slab: Slab<usize> = (0..300).collect();
+    slab.retain(|key, _| usize::from(key) % 2 == 0);
+    assert_eq!(slab.count_occupied_before(0.into()), 0);
+    assert_eq!(slab.count_occupied_before(1.into()), 1);
+    assert_eq!(slab.count_occupied_before(2.into()), 1);
+    assert_eq!(slab.count_occupied_before(200.into()), 100);
+    assert_eq!(slab.count_occupied_before(10_000.into()), 150);
+}
+
+#[test]
+fn select_nth_key() {
+    let mut slab: Slab<usize> = (0..300).collect();
+    slab.retain(|key, _| usize::from(key) % 2 == 0);
+    assert_eq!(slab.select_nth_key(0), Some(0.into()));
+    assert_eq!(slab.select_nth_key(100), Some(200.into()));
+    assert_eq!(slab.select_nth_key(149), Some(298.into()));
+    assert_eq!(slab.select_nth_key(150), None);
+    for n in [0, 33, 64, 149] {
+        let key = slab.select_nth_key(n).unwrap();
+        assert_eq!(slab.count_occupied_before(key), n);
+    }
+}
+
+#[test]
+fn swap_values() {
+    let mut slab: Slab<String> = ["a", "b", "c"].into_iter().map(String::from).collect();
+    slab.swap_values(0.into(), 2.into());
+    slab.swap_values(1.into(), 1.into());
+    assert_eq!(slab[0.into()], "c");
+    assert_eq!(slab[1.into()], "b");
+    assert_eq!(slab[2.into()], "a");
+}
+
+#[test]
+#[should_panic]
+fn swap_values_vacant() {
+    let mut slab: Slab<usize> = (0..3).collect();
+    slab.remove(1.into());
+    slab.swap_values(0.into(), 1.into());
+}
+
+#[test]
+fn set_algebra_keys() {
+    let mut lhs: Slab<usize> = (0..300).collect();
+    lhs.retain(|key, _| usize::from(key) % 2 == 0);
+    let mut rhs: Slab<&str> = (0..6).map(|_| "").collect();
+    rhs.retain(|key, _| usize::from(key) % 3 == 0);
+    let keys = |keys: &[usize]| -> Vec<beton::Key> { keys.iter().map(|&k| k.into()).collect() };
+
+    assert_eq!(lhs.intersection_keys(&rhs), keys(&[0]));
+    assert_eq!(rhs.union_keys(&lhs).len(), 151);
+    assert_eq!(rhs.union_keys(&lhs)[..4], keys(&[0, 2, 3, 4]));
+    assert_eq!(rhs.difference_keys(&lhs), keys(&[3]));
+    assert_eq!(lhs.difference_keys(&rhs).len(), 149);
+    assert_eq!(rhs.symmetric_difference_keys(&lhs)[..3], keys(&[2, 3, 4]));
+}
+
+#[test]
+fn batch_contains_all() {
+    let mut slab: Slab<usize> = (0..300).collect();
+    slab.remove(70.into());
+    assert!(slab.batch_contains_all(&[]));
+    assert!(slab.batch_contains_all(&[0.into(), 69.into(), 299.into()]));
+    assert!(!slab.batch_contains_all(&[0.into(), 70.into()]));
+    assert!(!slab.batch_contains_all(&[10_000.into()]));
+}
+
+#[test]
+fn group_by_key_prefix() {
+    let mut slab: Slab<usize> = (0..10).collect();
+    slab.retain(|key, _| [0, 3, 5, 9].contains(&usize::from(key)));
+
+    // Grouping by the top 62 bits groups keys in runs of 4.
+    let groups = slab.group_by_key_prefix::<62>();
+    assert_eq!(groups.len(), 3);
+    assert_eq!(groups[0], (0, vec![(0.into(), &0), (3.into(), &3)]));
+    assert_eq!(groups[1], (1, vec![(5.into(), &5)]));
+    assert_eq!(groups[2], (2, vec![(9.into(), &9)]));
+
+    let groups = slab.group_by_key_prefix::<0>();
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0].1.len(), 4);
+}
+
+#[test]
+fn first_vacant_key_after() {
+    let mut slab: Slab<usize> = (0..10).collect();
+    slab.remove(3.into());
+    assert_eq!(slab.first_vacant_key_after(0.into()), 3.into());
+    assert_eq!(slab.first_vacant_key_after(4.into()), 10.into());
+    assert_eq!(slab.first_vacant_key_after(50.into()), 50.into());
+
+    let capacity = slab.capacity();
+    assert_eq!(
+        slab.first_vacant_key_after((capacity + 5).into()),
+        (capacity + 5).into()
+    );
+}
+
+#[test]
+fn insert_hint() {
+    let mut slab: Slab<usize> = (0..4).collect();
+    assert_eq!(slab.insert_hint(10.into(), 10), 10.into());
+    assert_eq!(slab.insert_hint(2.into(), 4), 4.into());
+    assert_eq!(slab.insert_hint(1000.into(), 1000), 1000.into());
+    assert_eq!(slab.get(1000.into()), Some(&1000));
+    assert_eq!(slab.insert(5), 5.into());
+    assert_eq!(slab.len(), 8);
+}
+
+#[test]
+fn copy_to_slice() {
+    let mut slab: Slab<u32> = (0..5).collect();
+    slab.remove(1.into());
+
+    let mut dst = [0; 8];
+    assert_eq!(slab.copy_to_slice(&mut dst), 4);
+    assert_eq!(dst, [0, 2, 3, 4, 0, 0, 0, 0]);
+
+    let mut dst = [(0, 0); 2];
+    assert_eq!(slab.copy_to_slice_keyed(&mut dst), 2);
+    assert_eq!(dst, [(0, 0), (2, 2)]);
+}
+
+#[test]
+fn raw_index_words() {
+    let mut slab: Slab<usize> = (0..66).collect();
+    slab.remove(1.into());
+    let words = slab.raw_index_words();
+    assert_eq!(words[0], usize::MAX & !0b10);
+    assert_eq!(words[1], 0b11);
+}
+
+#[test]
+fn into_sorted_vec() {
+    let slab: Slab<char> = [(5.into(), 'c'), (0.into(), 'a'), (2.into(), 'b')]
+        .into_iter()
+        .collect();
+    assert_eq!(slab.into_sorted_vec(), vec!['a', 'b', 'c']);
+}
+
+#[test]
+fn keys_as_bitset() {
+    use std::mem::MaybeUninit;
+
+    let mut slab: Slab<String> = (0..70).map(|n| n.to_string()).collect();
+    slab.retain(|key, _| usize::from(key) % 3 == 0);
+    let bitset = slab.keys_as_bitset();
+    assert_eq!(bitset[0] & 0b1111, 0b1001);
+
+    let values: Vec<MaybeUninit<String>> = (0..70)
+        .map(|n| match n % 3 {
+            0 => MaybeUninit::new(n.to_string()),
+            _ => MaybeUninit::uninit(),
+        })
+        .collect();
+    // SAFETY: every third value is initialized, matching the bitset.
+    let copy = unsafe { Slab::from_keys_bitset(bitset, values) };
+    assert_eq!(copy.len(), slab.len());
+    assert!(copy.all(|key, value| slab.get(key) == Some(value)));
+    assert_eq!(copy.total_keys_ever_inserted(), copy.len() as u64);
+}
+
+#[test]
+fn min_max_occupied_key() {
+    let mut slab: Slab<usize> = (0..200).collect();
+    slab.retain(|key, _| (3..=150).contains(&usize::from(key)));
+    assert_eq!(slab.min_occupied_key(), Some(3.into()));
+    assert_eq!(slab.max_occupied_key(), Some(150.into()));
+
+    slab.clear();
+    assert_eq!(slab.min_occupied_key(), None);
+    assert_eq!(slab.max_occupied_key(), None);
+}
+
+#[test]
+fn batch_insert_at() {
+    use beton::BatchInsertError;
+
+    let mut slab: Slab<usize> = (0..3).collect();
+    let err = slab.batch_insert_at([(5.into(), 5), (1.into(), 1)]);
+    assert_eq!(err, Err(BatchInsertError::Occupied(1.into())));
+    let err = slab.batch_insert_at([(5.into(), 5), (5.into(), 6)]);
+    assert_eq!(err, Err(BatchInsertError::Duplicate(5.into())));
+    assert_eq!(slab.len(), 3);
+
+    slab.batch_insert_at([(300.into(), 300), (5.into(), 5)])
+        .unwrap();
+    assert_eq!(slab.len(), 5);
+    assert_eq!(slab.get(5.into()), Some(&5));
+    assert_eq!(slab.get(300.into()), Some(&300));
+}
+
+#[test]
+fn rotate() {
+    let mut slab: Slab<usize> = (0..3).collect();
+    let capacity = slab.capacity();
+    slab.remove(1.into());
+
+    let remap = slab.rotate_right(capacity - 1);
+    assert_eq!(
+        remap,
+        vec![(0.into(), (capacity - 1).into()), (2.into(), 1.into())]
+    );
+    assert_eq!(slab.get((capacity - 1).into()), Some(&0));
+    assert_eq!(slab.get(1.into()), Some(&2));
+    assert_eq!(slab.len(), 2);
+
+    slab.rotate_left(capacity - 1);
+    assert_eq!(slab.get(0.into()), Some(&0));
+    assert_eq!(slab.get(2.into()), Some(&2));
+    assert_eq!(slab.capacity(), capacity);
+}
+
+#[test]
+fn dedup_values() {
+    let mut slab: Slab<usize> = [1, 1, 2, 9, 2, 2, 1].into_iter().collect();
+    slab.remove(3.into());
+    assert_eq!(slab.dedup_values(), 3);
+    let values: Vec<_> = slab.into_iter().collect();
+    assert_eq!(values, vec![(0.into(), 1), (2.into(), 2), (6.into(), 1)]);
+}
+
+#[test]
+fn apply_permutation() {
+    let mut slab: Slab<String> = (0..4).map(|n| n.to_string()).collect();
+    slab.remove(1.into());
+    let capacity = slab.capacity();
+
+    // Reverse the key space.
+    let perm: Vec<usize> = (0..capacity).rev().collect();
+    slab.apply_permutation(&perm);
+    assert_eq!(slab.len(), 3);
+    assert_eq!(slab[(capacity - 1).into()], "0");
+    assert_eq!(slab[(capacity - 3).into()], "2");
+    assert_eq!(slab[(capacity - 4).into()], "3");
+    assert!(!slab.contains_key((capacity - 2).into()));
+
+    // A three-cycle: 0 -> 1 -> 2 -> 0.
+    let mut slab: Slab<usize> = (0..3).collect();
+    let mut perm: Vec<usize> = (0..slab.capacity()).collect();
+    perm[..3].copy_from_slice(&[1, 2, 0]);
+    slab.apply_permutation(&perm);
+    let pairs: Vec<_> = slab.into_iter().collect();
+    assert_eq!(pairs, vec![(0.into(), 2), (1.into(), 0), (2.into(), 1)]);
+}
+
+#[test]
+#[should_panic]
+fn apply_permutation_invalid() {
+    let mut slab: Slab<usize> = (0..3).collect();
+    let perm = vec![0; slab.capacity()];
+    slab.apply_permutation(&perm);
+}
+
+#[test]
+fn count_ones_in_word() {
+    let mut slab: Slab<usize> = (0..70).collect();
+    slab.remove(3.into());
+    assert_eq!(slab.count_ones_in_word(0), Some(63));
+    assert_eq!(slab.count_ones_in_word(1), Some(6));
+    assert_eq!(slab.count_ones_in_word(1000), None);
+}
+
+#[test]
+#[should_panic(expected = "key `42` not present (slab capacity: 128, len: 1)")]
+fn index_not_present() {
+    let mut slab = Slab::new();
+    slab.insert(1);
+    let _ = slab[42.into()];
+}
+
+#[test]
+#[should_panic(expected = "key `42` not present")]
+fn index_mut_not_present() {
+    let mut slab = Slab::new();
+    slab.insert(1);
+    slab[42.into()] = 2;
+}
+
+#[test]
+fn occupancy_histogram() {
+    let mut slab: Slab<usize> = (0..70).collect();
+    slab.remove(0.into());
+    assert_eq!(slab.capacity(), 128);
+    assert_eq!(slab.occupancy_histogram(4), vec![31, 32, 6, 0]);
+    assert_eq!(slab.occupancy_histogram(1), vec![69]);
+    assert_eq!(slab.occupancy_histogram(3), vec![42, 27, 0]);
+}
+
+#[test]
+fn drain_where() {
+    let mut slab: Slab<usize> = (0..6).collect();
+    let drained: Vec<_> = slab.drain_where(|_, value| *value >= 3).collect();
+    assert_eq!(drained, vec![(3.into(), 3), (4.into(), 4), (5.into(), 5)]);
+    assert_eq!(slab.len(), 3);
+
+    // Dropping the iterator early still removes every match.
+    drop(slab.drain_where(|key, _| key.index() == 1));
+    assert!(!slab.contains_key(1.into()));
+    assert_eq!(slab.len(), 2);
+}
+
+#[test]
+fn fold() {
+    let mut slab: Slab<usize> = (0..4).map(|n| n * 10).collect();
+    slab.remove(1.into());
+    let sum = slab.fold(0, |acc, key, value| acc + key.index() + value);
+    assert_eq!(sum, (2 + 3) + (20 + 30));
+    assert_eq!(Slab::<usize>::new().fold(7, |acc, _, _| acc + 1), 7);
+}
+
+#[test]
+fn find() {
+    let mut slab: Slab<usize> = (0..6).collect();
+    slab.remove(2.into());
+    let is_large = |_, value: &usize| *value >= 4;
+    assert_eq!(slab.find(|_, value| *value > 1), Some(3.into()));
+    assert_eq!(slab.find(|_, value| *value > 5), None);
+    assert_eq!(slab.find_from(1.into(), is_large), Some(4.into()));
+    assert_eq!(slab.find_from(5.into(), is_large), Some(5.into()));
+    assert_eq!(slab.find_from(100.into(), is_large), None);
+}
+
+#[test]
+fn is_subset_of_keys() {
+    let base: Slab<usize> = (0..200).collect();
+    let mut derived: Slab<&str> = Slab::new();
+    assert!(derived.is_subset_of_keys(&base));
+
+    derived.insert("a");
+    derived.insert("b");
+    assert!(derived.is_subset_of_keys(&base));
+    assert!(!base.is_subset_of_keys(&derived));
+
+    let mut small: Slab<usize> = (0..3).collect();
+    small.remove(1.into());
+    assert!(!derived.is_subset_of_keys(&small));
+}
+
+#[test]
+fn pack_values_to_vec() {
+    let mut slab: Slab<(f32, f32)> = [(1.0, 2.0), (3.0, 4.0), (5.0, 6.0)].into_iter().collect();
+    slab.remove(1.into());
+    let xs = slab.pack_values_to_vec(|(x, _)| *x);
+    assert_eq!(xs, vec![1.0, 5.0]);
+    assert_eq!(xs.capacity(), 2);
+    assert!(Slab::<u8>::new().pack_values_to_vec(|v| *v).is_empty());
+}
+
+#[test]
+fn keys_not_in() {
+    let position: Slab<usize> = (0..10).collect();
+    let mut velocity: Slab<usize> = (0..10).collect();
+    velocity.retain(|key, _| key.index() % 3 != 0);
+    let statics: Vec<_> = position.keys_not_in(&velocity).collect();
+    assert_eq!(statics, position.difference_keys(&velocity));
+    assert_eq!(statics, vec![0.into(), 3.into(), 6.into(), 9.into()]);
+}
+
+#[test]
+fn into_map() {
+    let mut slab: Slab<char> = "abc".chars().collect();
+    slab.remove(1.into());
+    let map: std::collections::HashMap<usize, char> = slab.into_map();
+    assert_eq!(map.len(), 2);
+    assert_eq!(map[&0], 'a');
+    assert_eq!(map[&2], 'c');
+}
+
+#[test]
+fn difference_update() {
+    let mut slab: Slab<usize> = (0..200).collect();
+    let mut other: Slab<()> = Slab::new();
+    for _ in 0..300 {
+        other.insert(());
+    }
+    other.retain(|key, _| key.index() % 2 == 1);
+    assert_eq!(slab.difference_update(&other), 100);
+    assert_eq!(slab.len(), 100);
+    assert!(slab.keys().all(|key| key.index() % 2 == 0));
+    assert_eq!(slab.difference_update(&other), 0);
+}
+
+#[test]
+fn iter_mut_safe() {
+    let mut slab: Slab<usize> = (0..300).collect();
+    slab.retain(|key, _| key.index() % 100 == 0);
+    for (key, value) in slab.iter_mut_safe() {
+        *value += key.index();
+    }
+    let values: Vec<_> = slab.values().copied().collect();
+    assert_eq!(values, vec![0, 200, 400]);
+}
+
+#[test]
+fn iter_keys_and_remove_where() {
+    let mut slab: Slab<usize> = (0..10).collect();
+    assert_eq!(slab.iter_keys_and_remove_where(|_, value| *value >= 7), 3);
+    assert_eq!(slab.len(), 7);
+    assert_eq!(
+        slab.iter_keys_and_remove_where(|key, _| key.index() == 0),
+        1
+    );
+    assert!(!slab.contains_key(0.into()));
+}
+
+#[test]
+fn iter_mut_with_remove() {
+    let mut slab: Slab<usize> = (0..6).collect();
+    let mut cursor = slab.iter_mut_with_remove();
+    while let Some((_, value)) = cursor.next() {
+        *value *= 10;
+        if *value % 20 == 0 {
+            cursor.remove_current();
+        }
+    }
+    let values: Vec<_> = slab.values().copied().collect();
+    assert_eq!(values, vec![10, 30, 50]);
+}
+
+#[test]
+fn shrink_index_to_fit() {
+    let mut slab: Slab<usize> = Slab::with_capacity(1024);
+    for n in 0..3 {
+        slab.insert(n);
+    }
+    let ptr: *const usize = &slab[1.into()];
+    slab.shrink_index_to_fit();
+    assert_eq!(slab.capacity(), 64);
+    assert_eq!(&slab[1.into()] as *const usize, ptr);
+
+    // Growing the slab again still works.
+    for n in 3..100 {
+        slab.insert(n);
+    }
+    assert_eq!(slab.len(), 100);
+    assert_eq!(slab[99.into()], 99);
+}
+
+#[test]
+fn extend_dense() {
+    let mut slab: Slab<usize> = (0..3).collect();
+    slab.remove(1.into());
+    let keys = slab.extend_dense(vec![10, 20, 30].into_iter());
+    assert_eq!(keys, vec![1.into(), 3.into(), 4.into()]);
+    assert_eq!(slab.len(), 5);
+    assert_eq!(slab[4.into()], 30);
+
+    let mut slab: Slab<usize> = Slab::new();
+    let keys = slab.extend_dense(0..200);
+    assert_eq!(keys.len(), 200);
+    assert_eq!(slab.capacity(), 256);
+}
+
+#[test]
+fn iter_with_context() {
+    let mut slab: Slab<&str> = ["a", "b", "c"].into_iter().collect();
+    slab.remove(1.into());
+    let out = slab.iter_with_context(String::new(), |out, key, value| {
+        out.push_str(&format!("{}={};", key.index(), value));
+    });
+    assert_eq!(out, "0=a;2=c;");
+}
+
+#[test]
+fn sorted_values_by() {
+    let slab: Slab<(&str, u32)> = [("b", 20), ("a", 30), ("c", 10)].into_iter().collect();
+    let ranking = slab.sorted_values_by(|a, b| b.1.cmp(&a.1));
+    assert_eq!(ranking, vec![&("a", 30), &("b", 20), &("c", 10)]);
+    assert_eq!(slab[0.into()], ("b", 20));
+}
+
+#[test]
+fn into_sorted_by_key() {
+    let slab: Slab<(&str, u32)> = [("b", 1), ("a", 2), ("c", 1)].into_iter().collect();
+    let values = slab.into_sorted_by_key(|value| value.1);
+    assert_eq!(values, vec![("b", 1), ("c", 1), ("a", 2)]);
+}
+
+#[test]
+fn iter_positions() {
+    let mut slab: Slab<bool> = [true, false, true, true].into_iter().collect();
+    slab.remove(2.into());
+    let active: Vec<_> = slab.iter_positions(|active| *active).collect();
+    assert_eq!(active, vec![0.into(), 3.into()]);
+}
+
+#[test]
+fn map_keys() {
+    let mut slab: Slab<char> = "abc".chars().collect();
+    slab.remove(1.into());
+    let mapped = slab.map_keys(|key| (key.index() * 50).into());
+    assert_eq!(mapped.len(), 2);
+    assert_eq!(mapped[0.into()], 'a');
+    assert_eq!(mapped[100.into()], 'c');
+    assert!(mapped.capacity() >= 101);
+}
+
+#[test]
+#[should_panic(expected = "duplicate key")]
+fn map_keys_duplicate() {
+    let slab: Slab<char> = "abc".chars().collect();
+    slab.map_keys(|_| 0.into());
+}
+
+#[test]
+fn first_n_keys() {
+    let mut slab: Slab<usize> = (0..5).collect();
+    slab.remove(1.into());
+    assert_eq!(slab.first_n_keys(2), vec![0.into(), 2.into()]);
+    assert_eq!(slab.first_n_keys(0), vec![]);
+    assert_eq!(slab.first_n_keys(usize::MAX).len(), 4);
+}
+
+#[test]
+fn take_first_n() {
+    let mut slab: Slab<usize> = (0..5).collect();
+    slab.remove(1.into());
+    assert_eq!(slab.take_first_n(2), vec![(0.into(), 0), (2.into(), 2)]);
+    assert_eq!(slab.len(), 2);
+    assert_eq!(slab.take_first_n(10), vec![(3.into(), 3), (4.into(), 4)]);
+    assert!(slab.is_empty());
+}
+
+#[test]
+fn intersection_keys_across_words() {
+    let position: Slab<usize> = (0..200).collect();
+    let mut velocity: Slab<usize> = (0..200).collect();
+    velocity.retain(|key, _| key.index() >= 190 || key.index() == 3);
+    let moving = position.intersection_keys(&velocity);
+    assert_eq!(moving.len(), 11);
+    assert_eq!(moving[0], 3.into());
+    assert_eq!(moving[10], 199.into());
+}
+
+#[test]
+fn total_keys_ever_inserted() {
+    let mut slab = Slab::new();
+    assert_eq!(slab.total_keys_ever_inserted(), 0);
+    let key = slab.insert(1);
+    slab.insert_hint(10.into(), 2);
+    slab.remove(key);
+    assert_eq!(slab.total_keys_ever_inserted(), 2);
+
+    slab.rotate_right(3);
+    assert_eq!(slab.total_keys_ever_inserted(), 2);
+
+    slab.clear();
+    slab.insert(3);
+    assert_eq!(slab.total_keys_ever_inserted(), 3);
+}
+
+#[test]
+fn iter_pages() {
+    let slab: Slab<usize> = (0..200).collect();
+    let counts: Vec<_> = slab.iter_pages(64).map(|page| page.count()).collect();
+    assert_eq!(counts, vec![64, 64, 64, 8]);
+    let total: usize = slab.iter_pages(7).flatten().map(|(_, value)| value).sum();
+    assert_eq!(total, (0..200).sum());
+}
+
+#[test]
+fn assert_valid() {
+    let mut slab: Slab<usize> = (0..200).collect();
+    slab.assert_valid();
+    slab.retain(|key, _| key.index() % 3 == 0);
+    slab.assert_valid();
+    slab.truncate(50);
+    slab.shrink_index_to_fit();
+    slab.assert_valid();
+    slab.clear();
+    slab.assert_valid();
+}
+
+#[test]
+fn len_hint() {
+    let mut slab: Slab<usize> = (0..3).collect();
+    slab.remove(1.into());
+    assert_eq!(slab.len_hint(), (2, Some(2)));
+    assert_eq!(Slab::<usize>::new().len_hint(), (0, Some(0)));
+}
+
+#[test]
+fn into_key_value_iter() {
+    let mut slab: Slab<char> = "abc".chars().collect();
+    slab.remove(0.into());
+    let pairs: Vec<(usize, char)> = slab.into_key_value_iter().collect();
+    assert_eq!(pairs, vec![(1, 'b'), (2, 'c')]);
+}
+
+#[test]
+fn print_occupied_bitmap() {
+    let mut slab: Slab<usize> = (0..300).collect();
+    slab.retain(|key, _| key.index() % 64 < 32);
+    slab.print_occupied_bitmap();
+    Slab::<usize>::new().print_occupied_bitmap();
+}
+
+#[test]
+fn diff_and_apply_delta() {
+    let mut old: Slab<char> = "abcd".chars().collect();
+    old.remove(3.into());
+    let mut new: Slab<char> = "_xc".chars().collect();
+    new.remove(0.into());
+    new.insert_hint(100.into(), 'z');
+
+    let delta = old.diff(&new);
+    assert_eq!(delta.removes, vec![0.into(), 1.into()]);
+    assert_eq!(delta.inserts, vec![(1.into(), 'x'), (100.into(), 'z')]);
+    assert!(new.diff(&new).is_empty());
+
+    old.apply_delta(delta);
+    assert_eq!(
+        old.iter().collect::<Vec<_>>(),
+        new.iter().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn try_shrink_to() {
+    let mut slab: Slab<usize> = Slab::with_capacity(1024);
+    slab.insert_hint(3.into(), 3);
+    slab.insert_hint(500.into(), 500);
+    assert_eq!(
+        slab.try_shrink_to(100),
+        Err(ShrinkError::OccupiedKeysInTruncatedRange {
+            keys: vec![500.into()]
+        })
+    );
+    assert_eq!(slab.capacity(), 1024);
+    assert_eq!(slab.len(), 2);
+
+    slab.remove(500.into());
+    assert_eq!(slab.try_shrink_to(100), Ok(()));
+    assert_eq!(slab.capacity(), 128);
+    assert_eq!(slab[3.into()], 3);
+    assert_eq!(slab.try_shrink_to(4096), Ok(()));
+    assert_eq!(slab.capacity(), 128);
+}
+
+#[test]
+fn fill_range() {
+    let mut slab: Slab<usize> = (0..4).collect();
+    slab.remove(2.into());
+    let keys = slab.fill_range(1..=5, 9);
+    assert_eq!(keys, vec![2.into(), 4.into(), 5.into()]);
+    let values: Vec<_> = slab.values().copied().collect();
+    assert_eq!(values, vec![0, 9, 9, 9, 9, 9]);
+
+    let mut slab: Slab<usize> = Slab::new();
+    let keys = slab.fill_range(..200, 1);
+    assert_eq!(keys.len(), 200);
+    assert_eq!(slab.len(), 200);
+}
+
+#[test]
+fn count_runs() {
+    assert_eq!(Slab::<usize>::new().count_runs(), 0);
+
+    let mut slab: Slab<usize> = (0..200).collect();
+    assert_eq!(slab.count_runs(), 1);
+
+    // Runs spanning word boundaries are counted once.
+    slab.remove(10.into());
+    slab.remove(150.into());
+    assert_eq!(slab.count_runs(), 3);
+
+    slab.retain(|key, _| key.index() % 2 == 0);
+    assert_eq!(slab.count_runs(), 98);
+}
+
+#[test]
+fn max_contiguous_vacant_span() {
+    let mut slab: Slab<usize> = (0..256).collect();
+    assert_eq!(slab.max_contiguous_vacant_span(), 0);
+
+    // A gap spanning a word boundary.
+    for key in 60..140 {
+        slab.remove(key.into());
+    }
+    slab.remove(200.into());
+    assert_eq!(slab.max_contiguous_vacant_span(), 80);
+
+    // A gap which runs up to the end of the capacity.
+    slab.truncate(100);
+    assert_eq!(slab.max_contiguous_vacant_span(), 68);
+
+    assert_eq!(Slab::<usize>::new().max_contiguous_vacant_span(), 128);
+}
+
+#[test]
+fn first_n_vacant_keys() {
+    let mut slab: Slab<usize> = (0..128).collect();
+    slab.remove(5.into());
+    slab.remove(64.into());
+    let keys = slab.first_n_vacant_keys(4);
+    assert_eq!(keys, vec![5.into(), 64.into(), 128.into(), 129.into()]);
+    for key in keys {
+        assert_eq!(slab.insert(0), key);
+    }
+    assert_eq!(slab.first_n_vacant_keys(0), vec![]);
+}
+
+#[test]
+fn entries_capacity() {
+    let mut slab: Slab<usize> = Slab::with_capacity(1000);
+    assert_eq!(slab.index_capacity(), slab.capacity());
+    assert_eq!(slab.index_capacity(), 1024);
+    assert!(slab.entries_capacity() >= 1000);
+
+    slab.insert(1);
+    slab.shrink_index_to_fit();
+    assert_eq!(slab.index_capacity(), 64);
+    assert!(slab.entries_capacity() >= 1000);
+}
+
+#[test]
+fn for_each_pair_mut() {
+    let mut slab: Slab<usize> = (0..4).collect();
+    slab.remove(1.into());
+    let mut pairs = vec![];
+    slab.for_each_pair_mut(|k1, k2, v1, v2| {
+        pairs.push((k1.index(), k2.index()));
+        *v1 += 1;
+        *v2 += 10;
+    });
+    assert_eq!(pairs, vec![(0, 2), (0, 3), (2, 3)]);
+    let values: Vec<_> = slab.values().copied().collect();
+    assert_eq!(values, vec![2, 13, 23]);
+}
+
+#[test]
+fn get_two_or_default_mut() {
+    let mut slab: Slab<usize> = (0..2).collect();
+    let (a, b) = slab.get_two_or_default_mut(1.into(), 100.into());
+    assert_eq!((*a, *b), (1, 0));
+    *a += 5;
+    *b += 7;
+    assert_eq!(slab[1.into()], 6);
+    assert_eq!(slab[100.into()], 7);
+    assert_eq!(slab.len(), 3);
+}
+
+#[test]
+#[should_panic(expected = "borrowed twice")]
+fn get_two_or_default_mut_same_key() {
+    let mut slab: Slab<usize> = Slab::new();
+    slab.get_two_or_default_mut(0.into(), 0.into());
+}
+
+#[test]
+fn count_occupied_between() {
+    let mut slab: Slab<usize> = (0..200).collect();
+    slab.retain(|key, _| key.index() % 10 == 0);
+    assert_eq!(slab.count_occupied_between(0.into(), 10.into()), 1);
+    assert_eq!(slab.count_occupied_between(0.into(), 11.into()), 2);
+    assert_eq!(slab.count_occupied_between(55.into(), 155.into()), 10);
+    assert_eq!(slab.count_occupied_between(0.into(), 10_000.into()), 20);
+    assert_eq!(slab.count_occupied_between(50.into(), 10.into()), 0);
+}
+
+#[test]
+fn is_contiguous() {
+    assert!(Slab::<usize>::new().is_contiguous());
+
+    let mut slab: Slab<usize> = (0..200).collect();
+    assert!(slab.is_contiguous());
+    slab.remove(0.into());
+    slab.remove(199.into());
+    assert!(slab.is_contiguous());
+    slab.remove(64.into());
+    assert!(!slab.is_contiguous());
+}
+
+#[test]
+fn select_min_max_key_where() {
+    let mut slab: Slab<usize> = (0..200).collect();
+    slab.remove(190.into());
+    let is_mid = |value: &usize| (100..=190).contains(value);
+    assert_eq!(slab.select_min_key_where(is_mid), Some(100.into()));
+    assert_eq!(slab.select_max_key_where(is_mid), Some(189.into()));
+    assert_eq!(
+        slab.select_max_key_where(|value| *value < 64),
+        Some(63.into())
+    );
+    assert_eq!(slab.select_min_key_where(|value| *value > 1000), None);
+    assert_eq!(slab.select_max_key_where(|value| *value > 1000), None);
+}
+
+#[test]
+fn windows_keyed() {
+    let slab: Slab<i32> = [1, 4, 9, 16].into_iter().collect();
+    let deltas: Vec<_> = slab
+        .windows_keyed::<2>()
+        .map(|[(_, a), (key, b)]| (key.index(), b - a))
+        .collect();
+    assert_eq!(deltas, vec![(1, 3), (2, 5), (3, 7)]);
+    assert_eq!(slab.windows_keyed::<5>().count(), 0);
+}
+
+#[test]
+fn to_sorted_by() {
+    let slab: Slab<u32> = [30, 10, 20, 10].into_iter().collect();
+    let sorted = slab.to_sorted_by(|a, b| a.cmp(b));
+    let keys: Vec<_> = sorted.iter().map(|(key, _)| key.index()).collect();
+    assert_eq!(keys, vec![1, 3, 2, 0]);
+    assert_eq!(*sorted[3].1, 30);
+}
+
+#[test]
+fn iter_occupied_chunks() {
+    let mut slab: Slab<usize> = (0..10_000).collect();
+    slab.retain(|key, _| key.index() % 1000 < 500);
+    let blocks: Vec<_> = slab.iter_occupied_chunks().collect();
+    assert_eq!(blocks.len(), 10);
+    assert_eq!(blocks[3].start, 3000.into());
+    assert!(blocks.iter().all(|block| block.len == 500));
+    assert_eq!(blocks.len(), slab.count_runs());
+}
+
+#[test]
+fn get_or_try_insert_with() {
+    let mut slab: Slab<u32> = Slab::new();
+    let err = slab.get_or_try_insert_with(3.into(), || "x".parse::<u32>());
+    assert!(err.is_err());
+    assert!(slab.is_empty());
+
+    let value = slab.get_or_try_insert_with(3.into(), || "7".parse::<u32>());
+    *value.unwrap() += 1;
+    assert_eq!(slab[3.into()], 8);
+
+    let value = slab.get_or_try_insert_with(3.into(), || "x".parse::<u32>());
+    assert_eq!(value, Ok(&mut 8));
+}
+
+#[test]
+fn with_default_value() {
+    let slab = Slab::with_default_value(200, 'x');
+    assert_eq!(slab.len(), 200);
+    assert!(slab.is_contiguous());
+    assert!(slab.values().all(|value| *value == 'x'));
+    slab.assert_valid();
+
+    assert!(Slab::with_default_value(0, 'x').is_empty());
+}
+
+#[test]
+fn iter_key_groups() {
+    let mut slab: Slab<usize> = (0..300).collect();
+    slab.retain(|key, _| key.index() % 50 == 0);
+    let sectors: Vec<_> = slab
+        .iter_key_groups(|key| key.index() / 100)
+        .map(|(sector, pairs)| (sector, pairs.len()))
+        .collect();
+    assert_eq!(sectors, vec![(0, 2), (1, 2), (2, 2)]);
+
+    // Groups which aren't contiguous are yielded once per run.
+    let parity: Vec<_> = slab
+        .iter_key_groups(|key| key.index() / 50 % 2)
+        .map(|(group, _)| group)
+        .collect();
+    assert_eq!(parity, vec![0, 1, 0, 1, 0, 1]);
+}
+
+#[test]
+fn map_in_place() {
+    let mut slab: Slab<f32> = [1.0, 2.0, 3.0].into_iter().collect();
+    slab.remove(1.into());
+    slab.map_in_place(|value| *value *= 2.0);
+    assert_eq!(slab.into_sorted_vec(), vec![2.0, 6.0]);
+}
+
+#[test]
+fn into_btreemap() {
+    let mut slab: Slab<char> = "abc".chars().collect();
+    slab.remove(1.into());
+    let map = slab.into_btreemap();
+    let pairs: Vec<_> = map
+        .iter()
+        .map(|(key, value)| (key.index(), *value))
+        .collect();
+    assert_eq!(pairs, vec![(0, 'a'), (2, 'c')]);
+
+    let slab = Slab::from_btreemap(map);
+    assert_eq!(slab.len(), 2);
+    assert_eq!(slab[2.into()], 'c');
+    assert!(!slab.contains_key(1.into()));
+}
+
+#[test]
+fn into_hashmap() {
+    let mut slab: Slab<char> = "abc".chars().collect();
+    slab.remove(1.into());
+    let map = slab.into_hashmap();
+    assert_eq!(map.len(), 2);
+    assert_eq!(map[&0.into()], 'a');
+    assert_eq!(map.get(&1.into()), None);
+}
+
+#[test]
+fn get_or_insert_many() {
+    let mut slab: Slab<usize> = (0..2).collect();
+    let keys = [100.into(), 1.into(), 5.into()];
+    let values = slab.get_or_insert_many(&keys, |key| key.index() * 10);
+    assert_eq!(values, vec![&mut 1000, &mut 1, &mut 50]);
+    for value in values {
+        *value += 1;
+    }
+    assert_eq!(slab[100.into()], 1001);
+    assert_eq!(slab[1.into()], 2);
+    assert_eq!(slab.len(), 4);
+}
+
+#[test]
+#[should_panic(expected = "appears more than once")]
+fn get_or_insert_many_duplicate() {
+    let mut slab: Slab<usize> = Slab::new();
+    slab.get_or_insert_many(&[3.into(), 1.into(), 3.into()], |_| 0);
+}
+
+#[test]
+fn bulk_swap() {
+    let mut slab: Slab<char> = "abc".chars().collect();
+    slab.bulk_swap(&[
+        (0.into(), 1.into()),
+        (1.into(), 2.into()),
+        (2.into(), 2.into()),
+    ]);
+    assert_eq!(slab.into_sorted_vec(), vec!['b', 'c', 'a']);
+}
+
+#[test]
+fn bulk_swap_vacant() {
+    let mut slab: Slab<char> = "abc".chars().collect();
+    slab.remove(2.into());
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        slab.bulk_swap(&[(0.into(), 1.into()), (1.into(), 2.into())]);
+    }));
+    assert!(result.is_err());
+    assert_eq!(slab.into_sorted_vec(), vec!['a', 'b']);
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn sample_random_key() {
+    use rand::SeedableRng;
+    let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+    let mut slab: Slab<usize> = Slab::new();
+    assert_eq!(slab.sample_random_key(&mut rng), None);
+
+    slab.extend(0..300);
+    slab.retain(|key, _| usize::from(key) % 7 == 0);
+    for _ in 0..1_000 {
+        let key = slab.sample_random_key(&mut rng).unwrap();
+        assert!(slab.contains_key(key));
+    }
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_for_each() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    let mut slab: Slab<usize> = (0..300).collect();
+    slab.retain(|key, _| usize::from(key) % 3 != 0);
+    let visits: Vec<_> = (0..300).map(|_| AtomicUsize::new(0)).collect();
+    slab.par_for_each(|key, value| {
+        assert_eq!(usize::from(key), *value);
+        visits[*value].fetch_add(1, Ordering::Relaxed);
+    });
+    for (n, count) in visits.iter().enumerate() {
+        let expected = usize::from(n % 3 != 0);
+        assert_eq!(count.load(Ordering::Relaxed), expected);
+    }
+}